@@ -16,6 +16,21 @@ enum NodeType
 	Identifier,
 	Literal,
 	Operator,
+	Assignment,
+	Range,
+	List,
+	Dict,
+	Tuple,
+	Set,
+	Enum,
+	Struct,
+	MemberAccess,
+	Attributes,
+	Attribute,
+	Use,
+	Alias,
+	Pass,
+	Unbounded,
 };
 
 struct Token
@@ -46,6 +61,21 @@ inline std::string nodeTypeString(NodeType type) {
 		"Identifier",
 		"Literal",
 		"Operator",
+		"Assignment",
+		"Range",
+		"List",
+		"Dict",
+		"Tuple",
+		"Set",
+		"Enum",
+		"Struct",
+		"MemberAccess",
+		"Attributes",
+		"Attribute",
+		"Use",
+		"Alias",
+		"Pass",
+		"Unbounded",
 	};
 	return vals[type];
 }
@@ -0,0 +1,135 @@
+"""Builders for well-formed TreeNodes, for code generators and tests.
+
+    fn = build.function("add").params(["x", "y"]).body(
+        build.binary("+", build.ident("x"), build.ident("y"))
+    ).node()
+
+Nodes get synthetic tokens (lineno 0, pos 0, `synthetic` set) since they
+have no source location.
+"""
+import re
+from ply import lex
+from . import lex_grammar
+from .helper_types import NodeType, TreeNode
+
+
+def _operator_types():
+    # Derive text -> token type from the lexer's fixed-text rules
+    ops = {}
+    for name in lex_grammar.tokens:
+        pattern = getattr(lex_grammar, f"t_{name}", None)
+        if isinstance(pattern, str):
+            text = re.sub(r"\\(.)", r"\1", pattern)
+            if re.fullmatch(pattern, text):
+                ops[text] = name
+    ops.update(lex_grammar.reserved)
+    return ops
+
+
+OPERATORS = _operator_types()
+
+
+def token(type, value):
+    tok = lex.Token()
+    tok.type, tok.value, tok.lineno, tok.pos, tok.end = type, value, 0, 0, 0
+    tok.synthetic = True
+    return tok
+
+
+def ident(name):
+    return TreeNode(NodeType.Identifier, token("ID", name))
+
+
+def literal(value):
+    if isinstance(value, str):
+        return TreeNode(NodeType.Literal, token("STR", f'"{value}"'))
+    return TreeNode(NodeType.Literal, token("FLOAT" if isinstance(value, float) else "INT", value))
+
+
+def _name(node):
+    # Strings are names; other plain values (numbers) are literals
+    if isinstance(node, str):
+        return ident(node)
+    return node if isinstance(node, TreeNode) else literal(node)
+
+
+def binary(op, left, right):
+    return TreeNode(NodeType.Operator, token(OPERATORS[op], op), [_name(left), _name(right)])
+
+
+def unary(op, operand):
+    return TreeNode(NodeType.Operator, token(OPERATORS[op], op), [_name(operand)])
+
+
+def range_(start=None, end=None, inclusive=False):
+    op = "..=" if inclusive else ".."
+    return TreeNode(
+        NodeType.Range,
+        token(OPERATORS[op], op),
+        [
+            _name(start) if start is not None else TreeNode(NodeType.Unbounded),
+            _name(end) if end is not None else TreeNode(NodeType.Unbounded),
+        ],
+    )
+
+
+def assign(target, value):
+    return TreeNode(NodeType.Assignment, token("EQUALS", "="), [_name(target), _name(value)])
+
+
+def call(name, *args):
+    return TreeNode(
+        NodeType.FunctionCall,
+        children=[ident(name), TreeNode(NodeType.Arguments, children=list(map(_name, args)))],
+    )
+
+
+def block(*statements):
+    return TreeNode(NodeType.Block, children=list(map(_name, statements)))
+
+
+def compilation_unit(*statements):
+    return TreeNode(NodeType.CompilationUnit, children=[block(*statements)])
+
+
+class FunctionBuilder:
+    def __init__(self, name):
+        self._name = name
+        self._params = []
+        self._body = []
+        self._attributes = []
+
+    def params(self, names):
+        self._params = list(names)
+        return self
+
+    def body(self, *statements):
+        self._body = list(statements)
+        return self
+
+    def attributes(self, *names):
+        self._attributes = list(names)
+        return self
+
+    def node(self):
+        # Child order matches QuarkParser.function(): name, Arguments, Block[, Attributes]
+        fn = TreeNode(
+            NodeType.Function,
+            token("FN", "fn"),
+            [
+                ident(self._name),
+                TreeNode(NodeType.Arguments, children=list(map(ident, self._params))),
+                block(*self._body),
+            ],
+        )
+        if self._attributes:
+            attrs = TreeNode(NodeType.Attributes, token("AT", "@"))
+            attrs.children = [
+                TreeNode(NodeType.Attribute, token("ID", name)) for name in self._attributes
+            ]
+            fn.children.append(attrs)
+        return fn
+
+
+def function(name):
+    return FunctionBuilder(name)
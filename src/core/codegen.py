@@ -6,7 +6,7 @@ import llvmlite.binding as llvm
 class QuarkCodeGen:
     def __init__(self) -> None:
         self.fl = ir.FloatType()
-        self.num = ir.IntType(32)
+        self.num = ir.IntType(64)
 
         self.sym_table = list()
         self.builder = ir.builder.IRBuilder()
@@ -27,8 +27,6 @@ class QuarkCodeGen:
                 rhs = self.generate(node.children[1])
 
                 match node.tok.type:
-                    case "EQUALS":
-                        return ir.GlobalVariable(self.cur_module, rhs.type, lhs)
                     case "PLUS":
                         return self.builder.add(lhs, rhs)
                     case "MINUS":
@@ -39,6 +37,11 @@ class QuarkCodeGen:
                         return self.builder.sdiv(lhs, rhs)
                     case _: return None
 
+        if node.type == NodeType.Assignment:
+            lhs = self.generate(node.children[0])
+            rhs = self.generate(node.children[1])
+            return ir.GlobalVariable(self.cur_module, rhs.type, lhs)
+
         if node.type == NodeType.Identifier:
             val = self.sym_table[node.tok.value]
             if not val:
@@ -0,0 +1,65 @@
+import os
+import copy
+import tomllib
+
+DEFAULTS = {
+    "edition": "2025",
+    "viz": {
+        "output": "treeviz.dot",
+        "clusters": True,
+        # 0 turns a limit off
+        "max_label": 0,
+        "merge_chains": False,
+        "max_nodes": 0,
+        # utils/themes.py; `styles` overrides parts of the chosen theme
+        "theme": "light",
+        "styles": {},
+    },
+    # Lint name -> "allow", "warn" or "deny"; -A/-W/-D on the command line win
+    "lints": {},
+    # Limits for the complexity lint and run_metrics; 0 turns one off
+    "metrics": {
+        "max_complexity": 10,
+        "max_nesting": 4,
+        "max_params": 5,
+        "max_lines": 50,
+    },
+}
+
+PROJECT_FILE = "quark.toml"
+USER_FILE = os.path.join(os.path.expanduser("~"), ".quarkrc")
+
+
+def _merge(base, override):
+    for key, val in override.items():
+        if isinstance(val, dict) and isinstance(base.get(key), dict):
+            _merge(base[key], val)
+        else:
+            base[key] = val
+    return base
+
+
+def find_project_config(start):
+    path = os.path.abspath(start)
+    if not os.path.isdir(path):
+        path = os.path.dirname(path)
+
+    while True:
+        candidate = os.path.join(path, PROJECT_FILE)
+        if os.path.isfile(candidate):
+            return candidate
+        parent = os.path.dirname(path)
+        if parent == path:
+            return None
+        path = parent
+
+
+def load(start=".", overrides=None):
+    """Resolves settings as CLI overrides > project quark.toml > ~/.quarkrc > defaults."""
+    config = copy.deepcopy(DEFAULTS)
+    for path in [USER_FILE, find_project_config(start)]:
+        if path and os.path.isfile(path):
+            with open(path, "rb") as f:
+                _merge(config, tomllib.load(f))
+
+    return _merge(config, overrides or {})
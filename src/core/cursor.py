@@ -0,0 +1,66 @@
+from .helper_types import NodeType
+
+
+class Cursor:
+    """A position in a tree that knows its parent, so context such as
+    "is this an assignment target?" can be asked of any node."""
+
+    def __init__(self, node, parent=None, index=None):
+        self.node = node
+        self.parent = parent
+        self.index = index
+
+    @classmethod
+    def root(cls, tree):
+        return cls(tree)
+
+    def children(self):
+        return [Cursor(c, self, i) for i, c in enumerate(self.node.children) if c]
+
+    def child(self, index):
+        node = self.node.children[index]
+        return Cursor(node, self, index) if node else None
+
+    def siblings(self):
+        return [c for c in self.parent.children() if c.index != self.index] if self.parent else []
+
+    def next_sibling(self):
+        return self._sibling(1)
+
+    def prev_sibling(self):
+        return self._sibling(-1)
+
+    def _sibling(self, step):
+        if not self.parent:
+            return None
+        i = self.index + step
+        kids = self.parent.node.children
+        while 0 <= i < len(kids) and kids[i] is None:
+            i += step
+        return Cursor(kids[i], self.parent, i) if 0 <= i < len(kids) else None
+
+    def ancestors(self):
+        cur = self.parent
+        while cur:
+            yield cur
+            cur = cur.parent
+
+    def path(self):
+        """Nodes from the root down to and including this one."""
+        return [c.node for c in reversed(list(self.ancestors()))] + [self.node]
+
+    def walk(self):
+        yield self
+        for child in self.children():
+            yield from child.walk()
+
+    def is_assignment_target(self):
+        # Climb through destructuring tuples to the enclosing Assignment
+        cur = self
+        while cur.parent and cur.parent.node.type == NodeType.Tuple:
+            cur = cur.parent
+        return (
+            cur.parent is not None
+            and cur.parent.node.type == NodeType.Assignment
+            and cur.index == 0
+        )
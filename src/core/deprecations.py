@@ -0,0 +1,37 @@
+from dataclasses import dataclass
+from typing import Callable
+from .diagnostics import Suggestion
+from .options import EDITIONS
+
+
+@dataclass(frozen=True)
+class Deprecation:
+    """Syntax that keeps working but warns from edition `since` on. `find`
+    takes the token list and tree and yields (token, replacement) pairs."""
+
+    name: str
+    since: str
+    message: str
+    find: Callable
+
+
+def _elif(tokens, tree):
+    for tok in tokens:
+        if tok.type == "ELIF":
+            yield tok, Suggestion("write 'elseif'", tok.pos, tok.pos + len(tok.value), "elseif")
+
+
+DEPRECATIONS = [
+    Deprecation("elif", "2025", "'elif' is deprecated; use 'elseif'.", _elif),
+]
+
+
+def active(edition):
+    return [d for d in DEPRECATIONS if EDITIONS.index(d.since) <= EDITIONS.index(edition)]
+
+
+def find(tokens, tree, edition):
+    """Lint findings for every deprecated construct in use under `edition`."""
+    for deprecation in active(edition):
+        for tok, fix in deprecation.find(tokens, tree):
+            yield "deprecated", deprecation.message, tok, [fix]
@@ -0,0 +1,76 @@
+import sys
+import json
+from dataclasses import dataclass, asdict
+from .error_codes import CATALOG
+
+
+@dataclass(frozen=True)
+class Suggestion:
+    message: str
+    pos: int
+    end: int
+    replacement: str
+    safe: bool = True
+
+
+class QuarkError(Exception):
+    def __init__(self, code, message, tok=None, severity="error", suggestions=None):
+        assert code in CATALOG, f"Unknown diagnostic code {code}"
+        super().__init__(message)
+        self.code = code
+        self.message = message
+        self.tok = tok
+        self.severity = severity
+        self.suggestions = suggestions or []
+
+    @property
+    def end(self):
+        return getattr(self.tok, "end", self.tok.pos) if self.tok else None
+
+    def to_dict(self, source):
+        line, col = source.line_col(self.tok.pos) if self.tok else (None, None)
+        end_line, end_col = source.line_col(self.end) if self.tok else (None, None)
+        return {
+            "file": source.path,
+            "line": line,
+            "column": col,
+            "end_line": end_line,
+            "end_column": end_col,
+            "pos": self.tok.pos if self.tok else None,
+            "end": self.end,
+            "severity": self.severity,
+            "code": self.code,
+            "message": self.message,
+            "suggestions": [asdict(s) for s in self.suggestions],
+        }
+
+
+def report(err, source, message_format="human"):
+    if message_format == "json":
+        print(json.dumps(err.to_dict(source)))
+    else:
+        loc = "{}:{}:".format(*source.line_col(err.tok.pos)) if err.tok else ""
+        print(f"{source.path}:{loc} {err.severity}[{err.code}]: {err.message}", file=sys.stderr)
+        if err.tok:
+            snippet(err, source)
+        for s in err.suggestions:
+            print(f"  help: {s.message}", file=sys.stderr)
+
+
+def snippet(err, source):
+    # The offending line, with carets under the token (clipped to the line)
+    line, _ = source.line_col(err.tok.pos)
+    text = source.line_text(line)
+    _, line_end = source.lines.line_span(line)
+    start = source.display_col(err.tok.pos)
+    end = source.display_col(min(err.end, line_end))
+    gutter = " " * len(str(line))
+    print(f"{line} | {text}", file=sys.stderr)
+    print(f"{gutter} | {' ' * start}{'^' * max(end - start, 1)}", file=sys.stderr)
+
+
+def apply_suggestions(source, suggestions):
+    # Splice from the back so earlier offsets stay valid
+    for s in sorted(suggestions, key=lambda s: s.pos, reverse=True):
+        source = source[: s.pos] + s.replacement + source[s.end :]
+    return source
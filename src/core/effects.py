@@ -0,0 +1,175 @@
+from dataclasses import dataclass, field
+from .helper_types import NodeType
+
+N = NodeType
+
+
+@dataclass
+class Effects:
+    node: object
+    # Why the function is effectful; empty for a pure function
+    reasons: list = field(default_factory=list)
+
+    @property
+    def pure(self):
+        return not self.reasons
+
+
+@dataclass
+class Analysis:
+    # Qualified function name (`outer.inner`, as in the call graph) -> Effects
+    functions: dict
+    # FunctionCall node id -> qualified name of the function it calls, for
+    # calls that resolve to a function in this file
+    calls: dict
+
+    def pure_call(self, node):
+        name = self.calls.get(node.id)
+        return name is not None and self.functions[name].pure
+
+
+def _targets(node):
+    if node.type == N.Tuple:
+        for child in node.children:
+            yield from _targets(child)
+    elif node.type == N.Identifier:
+        yield node.tok.value
+
+
+def _base(node):
+    while node.type == N.MemberAccess:
+        node = node.children[0]
+    return node.tok.value if node.type == N.Identifier else None
+
+
+def _declared(block, prefix):
+    """Names bound directly in `block` (not inside nested functions), as
+    name -> (kind, qualified name for functions)."""
+    names = {}
+
+    def visit(node):
+        match node.type:
+            case N.Function:
+                name = node.children[0].tok.value
+                names[name] = ("function", f"{prefix}.{name}" if prefix else name)
+                return
+            case N.Enum | N.Struct:
+                names[node.children[0].tok.value] = ("type", None)
+                return
+            case N.Use:
+                for child in node.children:
+                    while child.type == N.MemberAccess:
+                        child = child.children[-1]
+                    names[child.tok.value] = ("import", None)
+                return
+            case N.Assignment:
+                for name in _targets(node.children[0]):
+                    names.setdefault(name, ("variable", None))
+        for child in node.children:
+            if child:
+                visit(child)
+
+    visit(block)
+    return names
+
+
+def analyze(tree):
+    """Classifies every function in `tree` as pure or effectful. A function
+    is effectful if it reads a module-level variable or one from an enclosing
+    function, assigns a field of an object it did not create, or calls a
+    function that is effectful or not defined in this file (builtins doing
+    I/O included). Recursive functions are pure unless something else in
+    the cycle is not."""
+    functions, calls, edges = {}, {}, {}
+
+    def lookup(name, scopes):
+        for depth, scope in enumerate(scopes):
+            if name in scope:
+                return depth, *scope[name]
+        return None, None, None
+
+    def function(node, qualified, scopes):
+        effects = functions[qualified] = Effects(node)
+        edges[qualified] = []
+        body = node.children[2]
+        own = {p.tok.value: ("param", None) for p in node.children[1].children}
+        own.update(_declared(body, qualified))
+        scopes = [own] + scopes
+        owned = {n for n, (kind, _) in own.items() if kind == "variable"}
+
+        def reason(text):
+            if text not in effects.reasons:
+                effects.reasons.append(text)
+
+        def visit(node):
+            match node.type:
+                case N.Function:
+                    function(node, lookup(node.children[0].tok.value, scopes)[2], scopes)
+                    return
+                case N.Use | N.Enum | N.Struct:
+                    return
+                case N.FunctionCall:
+                    callee = node.children[0].tok.value
+                    _, kind, target = lookup(callee, scopes)
+                    if kind == "function":
+                        calls[node.id] = target
+                        edges[qualified].append(target)
+                    elif kind is None:
+                        reason(f"calls '{callee}', which is not defined in this file")
+                    elif kind != "type":
+                        reason(f"calls '{callee}', which is not known statically")
+                    visit(node.children[1])
+                    return
+                case N.Assignment:
+                    target, value = node.children
+                    visit(value)
+                    if target.type == N.MemberAccess:
+                        base = _base(target)
+                        if base not in owned:
+                            reason(f"assigns a field of '{base}'")
+                        visit(target)
+                    return
+                case N.MemberAccess:
+                    visit(node.children[0])
+                    return
+                case N.Identifier:
+                    depth, kind, _ = lookup(node.tok.value, scopes)
+                    if kind in ["variable", "param"] and depth == len(scopes) - 1:
+                        reason(f"reads the module-level variable '{node.tok.value}'")
+                    elif kind in ["variable", "param"] and depth > 0:
+                        reason(f"reads '{node.tok.value}' from an enclosing function")
+            for child in node.children:
+                if child:
+                    visit(child)
+
+        visit(body)
+
+    def module(node, scopes):
+        if node.type == N.Function:
+            function(node, node.children[0].tok.value, scopes)
+            return
+        if node.type == N.FunctionCall:
+            _, kind, target = lookup(node.children[0].tok.value, scopes)
+            if kind == "function":
+                calls[node.id] = target
+        for child in node.children:
+            if child:
+                module(child, scopes)
+
+    top = tree.children[0] if tree.children else None
+    if top:
+        module(top, [_declared(top, None)])
+
+    # Effects spread from callee to caller until nothing changes
+    changed = True
+    while changed:
+        changed = False
+        for name, effects in functions.items():
+            if not effects.pure:
+                continue
+            impure = next((c for c in edges[name] if not functions[c].pure), None)
+            if impure:
+                effects.reasons.append(f"calls '{impure}', which is effectful")
+                changed = True
+
+    return Analysis(functions, calls)
@@ -0,0 +1,297 @@
+# Extended descriptions for every diagnostic code, shown by `run_explain`.
+# Each entry is (summary, explanation); explanations include an example.
+CATALOG = {
+    "QK0001": (
+        "integer literal out of range",
+        """Integer literals are signed 64-bit values. A literal larger than
+9223372036854775807 cannot be represented and is rejected by the lexer.
+
+    x = 9223372036854775808    // error
+
+Use a float literal if an approximate value is acceptable:
+
+    x = 9223372036854775808.0""",
+    ),
+    "QK0002": (
+        "expected an indented block",
+        """A line ending in ':' followed by a newline must be followed by a
+block indented deeper than the line that opened it.
+
+    fn add x, y:
+    x + y           // error: body is not indented
+
+Indent the body:
+
+    fn add x, y:
+        x + y
+
+A body cannot be empty; use `pass` (or `todo` for one still to be written):
+
+    fn later: todo""",
+    ),
+    "QK0003": (
+        "unexpected indentation",
+        """Indentation may only increase at the start of a new block, i.e.
+after a line ending in ':'.
+
+    x = 1
+        y = 2       // error
+
+Remove the extra indentation, or open a block on the previous line.""",
+    ),
+    "QK0004": (
+        "inconsistent indentation",
+        """When a block ends, the next line must line up with one of the
+enclosing blocks.
+
+    fn f x:
+            a = 1
+        b = 2       // error: matches no enclosing level
+
+Align the line with an enclosing block.""",
+    ),
+    "QK0005": (
+        "unexpected end of input",
+        """The file ended while the parser still expected more tokens, e.g.
+an unfinished expression or function signature.
+
+    fn          // error: missing name, arguments and body""",
+    ),
+    "QK0006": (
+        "expected token",
+        """The parser required a specific token at this point, such as the ':'
+ending a function signature or a closing ')'.
+
+    fn add x, y     // error: expected ':'
+        x + y
+
+The diagnostic usually carries a suggestion; `run_check --fix` applies it.""",
+    ),
+    "QK0007": (
+        "expected expression",
+        """An operator or delimiter appeared where an expression must start.
+
+    x = 1 +         // error: '+' has no right operand""",
+    ),
+    "QK0008": (
+        "invalid assignment target",
+        """Only a name, a field (`p.x`), or a tuple of those to destructure
+into, can appear on the left of '='. Assignment is a statement-level form and does not
+chain.
+
+    1 = x           // error: cannot assign to a literal
+    a = b = 0       // error: chained assignment
+    @f (a = 1)      // error: assignment inside an expression
+
+Assign each name separately, or destructure a tuple:
+
+    a = 0
+    (a, b) = (0, 0)""",
+    ),
+    "QK0009": (
+        "inclusive range without an end",
+        """`a..=b` includes its end bound, so the end cannot be omitted.
+Open-ended ranges use the exclusive form.
+
+    xs = 1..=       // error
+
+Write `1..` for a range with no upper bound, or give the end:
+
+    xs = 1..=10""",
+    ),
+    "QK0010": (
+        "attributes without a function",
+        """Attributes such as `@[inline]` annotate the function that follows
+them, so they must be followed by a function definition.
+
+    @[test]
+    x = 1           // error
+
+Move the attribute onto a function:
+
+    @[test]
+    fn check_add: @assert_eq 3, 3""",
+    ),
+    "QK0011": (
+        "conflicting import",
+        """Each name brought into scope by `use` must be unique, whether it
+is a module, a module alias or a selected name.
+
+    use math: sqrt
+    use fastmath: sqrt      // error: 'sqrt' is already imported
+
+Alias one of them:
+
+    use math: sqrt
+    use fastmath: sqrt as fast_sqrt""",
+    ),
+    "QK0012": (
+        "experimental syntax",
+        """Some syntax is still settling and must be enabled explicitly, so
+that changing it later cannot break existing programs.
+
+    x = a ?? 0      // error without --feature coalesce
+
+Pass the named feature to the driver, e.g.
+
+    python -m drivers.run_check --feature coalesce file.qrk""",
+    ),
+    "QK0013": (
+        "assignment used as a condition",
+        """`=` assigns and `==` compares. An `if` or `elseif` test must be a
+condition, so an assignment there is almost always a typo.
+
+    if x = 1:       // error
+        done
+
+Compare instead:
+
+    if x == 1:
+        done""",
+    ),
+    "QK0014": (
+        "misspelled 'elseif'",
+        """Further branches of an `if` are introduced with the single keyword
+`elseif`; `else if` is not Quark syntax.
+
+    if age < 16:
+        "too young"
+    else if age == 16:  // error
+        "learner's permit"
+
+Write `elseif age == 16:` instead. `elseif` is reserved from the 2025
+edition on; the 2024 edition spells it `elif`.""",
+    ),
+    "QK0015": (
+        "unused variable",
+        """A warning (lint `unused-variable`). A name assigned inside a function
+is never read, which usually means a typo or leftover code.
+
+    fn area w, h:
+        scale = 2       // warning: 'scale' is never read
+        w * h
+
+Remove the assignment, or start the name with '_' to keep it deliberately.""",
+    ),
+    "QK0016": (
+        "shadowed name",
+        """A warning (lint `shadowing`). A parameter or local variable has the
+same name as a binding in an enclosing scope, hiding it inside the function.
+
+    limit = 10
+    fn clamp x, limit:  // warning: 'limit' shadows line 1
+        ...
+
+Rename one of them, or allow the lint with `-A shadowing`.""",
+    ),
+    "QK0017": (
+        "juxtaposed expressions",
+        """A warning (lint `juxtaposition`). A name is followed by another
+expression on the same line. Quark has no implicit calls, so this is two
+separate statements.
+
+    print x         // warning
+
+Call functions with '@':
+
+    @print x""",
+    ),
+    "QK0018": (
+        "deprecated syntax",
+        """A warning (lint `deprecated`). The construct still works but has
+been superseded in the edition this file is built with. The warning carries
+the replacement, which `run_check --fix` applies.
+
+    if a:
+        x
+    elif b:             // warning from the 2025 edition on
+        y
+
+`python -m drivers.run_check --fix file.qrk` rewrites it to `elseif b:`.""",
+    ),
+    "QK0019": (
+        "chained non-associative operator",
+        """Some operators have no natural grouping, so two of them cannot
+appear side by side at the same level. Ranges are one such operator.
+
+    r = 1..5..10    // error
+
+Add parentheses to say which grouping is meant. `run_dump --precedence`
+lists the associativity of every operator.""",
+    ),
+    "QK0020": (
+        "import cycle",
+        """Modules imported with `use` must not import each other in a loop,
+directly or through other modules.
+
+    // a.qrk
+    use b
+    // b.qrk
+    use a           // error: a -> b -> a
+
+Move what both need into a third module that neither imports back.""",
+    ),
+    "QK0021": (
+        "possibly uninitialized variable",
+        """A warning (lint `uninitialized`). A local variable is read at a
+point where some path through the function has not assigned it yet.
+
+    fn label n:
+        if n > 0:
+            s = "positive"
+        s               // warning: 's' may be read before it is assigned
+
+Assign it on every path, for example in an `else` branch or before the
+`if`. Names defined at module level are not checked.""",
+    ),
+    "QK0022": (
+        "unused assignment",
+        """A warning (lint `unused-assignment`). A value stored in a local
+variable is replaced on every path before anything reads it.
+
+    fn first xs:
+        item = 0        // warning: overwritten below
+        item = xs.head
+        item
+
+Remove the first assignment, or read the value before replacing it.""",
+    ),
+    "QK0023": (
+        "discarded result of a pure function",
+        """A warning (lint `discarded-result`). A function that only computes
+a value is called as a statement and its result is dropped, so the call
+does nothing.
+
+    fn square x: x * x
+    @square 4       // warning
+
+Calls are statements, so the result cannot be stored; make the call the
+last statement of a function, whose value it then returns, or remove it.
+A function counts as effectful if it reads or assigns state outside
+itself or calls a function not defined in the file, such as an I/O
+builtin.""",
+    ),
+    "QK0024": (
+        "function over a metrics limit",
+        """A warning (lint `complexity`). A function exceeds one of the limits
+in the `[metrics]` table of quark.toml:
+
+    [metrics]
+    max_complexity = 10     # 1 + each if/elseif test
+    max_nesting = 4         # depth of nested ifs
+    max_params = 5
+    max_lines = 50
+
+Split the function up, or raise the limit; 0 turns a limit off.
+`run_metrics` prints every function's figures.""",
+    ),
+    "QK0025": (
+        "illegal character",
+        """The lexer found a character that cannot start any token, outside a
+string or comment.
+
+    price = $5      // error: '$'
+
+Remove the character, or put it inside a string.""",
+    ),
+}
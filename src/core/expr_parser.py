@@ -1,60 +1,238 @@
+from dataclasses import replace
 from .helper_types import *
+from .diagnostics import QuarkError
+from .precedence import INFIX, PREFIX, Assoc
 
 
 class ExprParser:
+    # Called with every new ExprParser, so code outside this module can add
+    # syntax through register_prefix/register_infix
+    extensions = []
+
     def __init__(self, parser):
         self.parser = parser
-        self.rules = [
-            Rule("PLUS", Precedence.Term, infix=self.binary),
-            Rule("MINUS", Precedence.Term, prefix=self.unary, infix=self.binary),
-            Rule("MULTIPLY", Precedence.Factor, infix=self.binary),
-            Rule("DIVIDE", Precedence.Factor, infix=self.binary),
-            Rule("EQUALS", Precedence.Assignment, infix=self.binary),
-            Rule("NE", Precedence.Zero, prefix=self.unary),
-            Rule("INT", Precedence.Zero, prefix=self.number),
-            Rule("FLOAT", Precedence.Zero, prefix=self.number),
-            Rule("ID", Precedence.Zero, prefix=self.identifier),
-            Rule("LPAR", Precedence.Zero, prefix=self.paren),
-        ]
+        # Per-parser copies of the operator tables, so extensions stay local
+        self.prefix_bindings = dict(PREFIX)
+        self.infix_bindings = dict(INFIX)
+        self.rules = {}
+
+        # Operands; operator rules come from the precedence tables
+        self.register_prefix("INT", self.literal)
+        self.register_prefix("FLOAT", self.literal)
+        self.register_prefix("STR", self.literal)
+        self.register_prefix("ID", self.identifier)
+        self.register_prefix("LPAR", self.paren)
+        self.register_prefix("LSQUARE", self.list)
+        self.register_prefix("LBRACE", self.brace)
+
+        prefix = {NodeType.Operator: self.unary, NodeType.Range: self.range_to}
+        infix = {
+            NodeType.Operator: self.binary,
+            NodeType.Assignment: self.assignment,
+            NodeType.Range: self.range,
+            NodeType.MemberAccess: self.member,
+        }
+        for type, binding in PREFIX.items():
+            self.register_prefix(type, prefix[binding.kind], binding)
+        for type, binding in INFIX.items():
+            self.register_infix(type, infix[binding.kind], binding)
+
+        for extend in ExprParser.extensions:
+            extend(self)
+
+    def register_prefix(self, type, parselet, binding=None):
+        """`parselet()` runs once a `type` token starting an expression has
+        been consumed (it is `self.parser.prev`) and returns the node.
+        Operators pass their `binding`; operands have none."""
+        if binding:
+            self.prefix_bindings[type] = binding
+        rule = self.rules.get(type, Rule(type, Precedence.Zero))
+        self.rules[type] = replace(rule, prefix=parselet)
+
+    def register_infix(self, type, parselet, binding):
+        """`parselet(left)` runs once a `type` token after the complete
+        operand `left` has been consumed, and returns the combined node."""
+        self.infix_bindings[type] = binding
+        rule = self.rules.get(type, Rule(type, binding.precedence))
+        self.rules[type] = replace(rule, precedence=binding.precedence, infix=parselet)
 
     def rule(self, tok_type):
-        return next(filter(lambda x: x.type == tok_type, self.rules))
+        return self.rules.get(tok_type)
+
+    # Token types that can start an expression, operands before operators
+    def starters(self):
+        starters = [t for t, r in self.rules.items() if r.prefix]
+        return sorted(starters, key=lambda t: t in self.prefix_bindings)
 
+    # A comma makes a parenthesized expression a tuple: `()`, `(a,)`, `(a, b)`
     def paren(self):
+        lpar = self.parser.prev
+        if self.parser.cur.type == "RPAR":
+            self.parser.consume()
+            return TreeNode(NodeType.Tuple, lpar)
+
         expr = self.parse()
+        if self.parser.cur.type != "COMMA":
+            self.parser.expect("RPAR")
+            return expr
+
+        node = TreeNode(NodeType.Tuple, lpar, [expr])
+        while self.parser.cur.type == "COMMA":
+            self.parser.consume()
+            if self.parser.cur.type == "RPAR":
+                break
+            node.children.append(self.parse())
+
         self.parser.expect("RPAR")
-        return expr
+        return node
+
+    def list(self):
+        node = TreeNode(NodeType.List, self.parser.prev)
+        while self.parser.cur.type != "RSQUARE":
+            node.children.append(self.parse())
+            if self.parser.cur.type != "COMMA":
+                break
+            self.parser.consume()
+
+        self.parser.expect("RSQUARE")
+        return node
+
+    # `{}` is the empty dict; otherwise a ':' after the first element picks
+    # a dict over a set. Dict children alternate key, value.
+    def brace(self):
+        lbrace = self.parser.prev
+        if self.parser.cur.type == "RBRACE":
+            self.parser.consume()
+            return TreeNode(NodeType.Dict, lbrace)
+
+        first = self.parse()
+        is_dict = self.parser.cur.type == "COLON"
+        if not is_dict:
+            self.parser.require("sets", lbrace)
+        node = TreeNode(NodeType.Dict if is_dict else NodeType.Set, lbrace, [first])
+        while True:
+            if is_dict:
+                self.parser.expect("COLON")
+                node.children.append(self.parse())
+            if self.parser.cur.type != "COMMA":
+                break
+            self.parser.consume()
+            if self.parser.cur.type == "RBRACE":
+                break
+            node.children.append(self.parse())
+
+        self.parser.expect("RBRACE")
+        return node
 
     def identifier(self):
         return TreeNode(NodeType.Identifier, self.parser.prev)
 
-    def number(self):
+    def literal(self):
         return TreeNode(NodeType.Literal, self.parser.prev)
 
+    # `not`/`~` binds looser than comparisons: `not a == b` is `not (a == b)`
     def unary(self):
         node = TreeNode(NodeType.Operator, self.parser.prev)
-        node.children.append(self.parse(precedence=Precedence.Unary))
+        binding = self.prefix_bindings[node.tok.type]
+        node.children.append(self.parse(precedence=binding.right_precedence()))
         return node
 
     def binary(self, left):
         node = TreeNode(NodeType.Operator, self.parser.prev)
-        rule = self.rule(node.tok.type)
-        node.children.extend([left, self.parse(precedence=rule.precedence + 1)])
+        if node.tok.type == "COALESCE":
+            self.parser.require("coalesce", node.tok)
+        binding = self.infix_bindings[node.tok.type]
+        node.children.extend([left, self.parse(precedence=binding.right_precedence())])
+        return node
+
+    def at_expr_end(self):
+        return self.parser.cur.type in [
+            "RPAR",
+            "RSQUARE",
+            "RBRACE",
+            "NEWLINE",
+            "COMMA",
+            "COLON",
+            "SEMI",
+            "EOF",
+        ]
+
+    # Ranges keep two children; an open bound is an Unbounded leaf, e.g. `n..`
+    # is [n, Unbounded]
+    def range(self, start):
+        node = TreeNode(NodeType.Range, self.parser.prev)
+        if self.at_expr_end():
+            if node.tok.type == "RANGEINCL":
+                raise QuarkError("QK0009", "Inclusive range needs an end bound.", node.tok)
+            node.children.extend([start, TreeNode(NodeType.Unbounded)])
+        else:
+            binding = self.infix_bindings[node.tok.type]
+            node.children.extend([start, self.parse(precedence=binding.right_precedence())])
         return node
 
+    def range_to(self):
+        node = TreeNode(NodeType.Range, self.parser.prev)
+        binding = self.prefix_bindings[node.tok.type]
+        node.children.extend(
+            [TreeNode(NodeType.Unbounded), self.parse(precedence=binding.right_precedence())]
+        )
+        return node
+
+    def member(self, obj):
+        node = TreeNode(NodeType.MemberAccess, self.parser.prev)
+        node.children.extend([obj, TreeNode(NodeType.Identifier, self.parser.expect("ID"))])
+        return node
+
+    def is_target(self, node):
+        if node.type == NodeType.Tuple:
+            return len(node.children) > 0 and all(map(self.is_target, node.children))
+        return node.type in [NodeType.Identifier, NodeType.MemberAccess]
+
+    # Statements parse `=` themselves (QuarkParser.assignment) and stop the
+    # expression before it, so reaching it here means it is nested
+    def assignment(self, target):
+        raise QuarkError(
+            "QK0008",
+            "Assignment is a statement and cannot appear inside an expression.",
+            self.parser.prev,
+        )
+
     def parse(self, precedence=Precedence.Assignment):
-        prefix = self.rule(self.parser.consume().type).prefix
+        rule = self.rule(self.parser.consume().type)
+        prefix = rule.prefix if rule else None
 
         if not prefix:
-            raise Exception("Expected expression.")
+            expected = ", ".join(map(self.parser.describe, self.starters()))
+            raise QuarkError(
+                "QK0007",
+                f"Expected an expression, found {self.parser.describe(self.parser.prev.type)}; "
+                f"expected one of: {expected}.",
+                self.parser.prev,
+            )
 
+        # Binding of the last operator applied at this level, to reject
+        # chains of non-associative operators such as `a..b..c` or `..b..c`
+        last = self.prefix_bindings.get(rule.type)
         expr = prefix()
 
         while (
-            self.parser.cur.type not in ["RPAR", "NEWLINE", "COMMA", "COLON"]
-            and self.rule(self.parser.cur.type).precedence >= precedence
+            not self.at_expr_end()
+            and self.parser.cur.type in self.infix_bindings
+            and self.infix_bindings[self.parser.cur.type].precedence >= precedence
         ):
+            binding = self.infix_bindings[self.parser.cur.type]
+            if binding.assoc == Assoc.NonAssoc and last and last.precedence == binding.precedence:
+                self.chained(binding, self.parser.cur)
             infix = self.rule(self.parser.consume().type).infix
             expr = infix(expr)
+            last = binding
 
         return expr
+
+    def chained(self, binding, tok):
+        raise QuarkError(
+            "QK0019",
+            f"{self.parser.describe(tok.type)} cannot follow another {binding.kind} operator; "
+            "add parentheses to group them.",
+            tok,
+        )
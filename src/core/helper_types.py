@@ -17,6 +17,22 @@ class NodeType(Enum):
     Identifier = 8
     Literal = 9
     Operator = 10
+    Assignment = 11
+    Range = 12
+    List = 13
+    Dict = 14
+    Tuple = 15
+    Set = 16
+    Enum = 17
+    Struct = 18
+    MemberAccess = 19
+    Attributes = 20
+    Attribute = 21
+    Use = 22
+    Alias = 23
+    Pass = 24
+    # Stands in for the missing bound of an open range
+    Unbounded = 25
 
     def __str__(self):
         return self._name_
@@ -26,24 +42,72 @@ class NodeType(Enum):
 class Precedence:
     Zero = 0
     Assignment = 1
-    Term = 2
-    Factor = 3
-    Unary = 4
+    Not = 2
+    Coalesce = 3
+    Equality = 4
+    Comparison = 5
+    Range = 6
+    Term = 7
+    Factor = 8
+    Unary = 9
+    Member = 10
 
 
-@dataclass
+@dataclass(eq=False)
 class TreeNode:
     type: NodeType
     tok: Token = None
     children: list = field(default_factory=list)
+    # Key for side tables (types, diagnostics, coverage); see assign_ids()
+    id: int = None
 
     def __str__(self):
         return f"{self.type}" + (f"[{self.tok.value}]" if self.tok else "")
 
+    def key(self, strict=False):
+        """Structural identity; positions are only included when `strict`."""
+        tok = None
+        if self.tok:
+            tok = (self.tok.type, self.tok.value)
+            if strict:
+                tok += (self.tok.lineno, self.tok.pos)
+        kids = tuple(c.key(strict) if c else None for c in self.children)
+        return (self.type, tok, kids)
+
+    # Equality and hashing ignore positions and ids, so a reparsed or
+    # hand-built tree compares equal to the original
+    def __eq__(self, other):
+        return isinstance(other, TreeNode) and self.key() == other.key()
+
+    def __hash__(self):
+        return hash(self.key())
+
+    def eq_strict(self, other):
+        return isinstance(other, TreeNode) and self.key(True) == other.key(True)
+
+    def walk(self, path=()):
+        path = path + (self,)
+        yield self, path
+        for child in self.children:
+            if child:
+                yield from child.walk(path)
+
     def print(self, level=0):
         print("\t" * level + str(self))
         for child in self.children:
-            child.print(level + 1)
+            if child:
+                child.print(level + 1)
+
+
+def assign_ids(tree):
+    """Numbers nodes in preorder. Nodes that already have an id keep it and
+    new ones continue after the largest, so ids survive later rewrites."""
+    nodes = [node for node, _ in tree.walk()]
+    next_id = max((n.id for n in nodes if n.id is not None), default=-1) + 1
+    for node in nodes:
+        if node.id is None:
+            node.id, next_id = next_id, next_id + 1
+    return tree
 
 
 @dataclass(frozen=True)
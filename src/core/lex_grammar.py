@@ -1,4 +1,6 @@
-reserved = {
+from .diagnostics import QuarkError
+
+reserved_2024 = {
     "use": "USE",
     "module": "MODULE",
     "in": "IN",
@@ -13,6 +15,22 @@ reserved = {
     "class": "CLASS",
 }
 
+# Each edition may only add keywords, so a program written for an older
+# edition keeps lexing the same way when pinned to it
+reserved = {
+    **reserved_2024,
+    "as": "AS",
+    "is": "IS",
+    "not": "NOT",
+    "enum": "ENUM",
+    "struct": "STRUCT",
+    "elseif": "ELSEIF",
+    "pass": "PASS",
+    "todo": "TODO",
+}
+
+KEYWORDS = {"2024": reserved_2024, "2025": reserved}
+
 tokens = (
     "ID",  # Identifiers
     "PLUS",  # +
@@ -21,30 +39,34 @@ tokens = (
     "DIVIDE",  # /
     "MODULO",  # %
     "AMPER", # &
-    "NOT",  # ~
+    "NOT",  # ~ or not
     "EQUALS",  # =
     "LT",  # <
     "GT",  # >
     "LTE",  # <=
     "GTE",  # >=
     "DEQ",  # ==
-    "NE",  # #
+    "NE",  # !=
+    "COALESCE",  # ??
     "LPAR",  # (
     "RPAR",  # )
-    "LBRACE",  # [
-    "RBRACE",  # ]
-    "BLOCKSTART",  # {
-    "BLOCKEND",  # }
+    "LSQUARE",  # [
+    "RSQUARE",  # ]
+    "LBRACE",  # {
+    "RBRACE",  # }
     "INT",  # int
     "FLOAT",  # float
     "STR",  # str
     "AT",   # @
     "DOT",  # .
+    "RANGE",  # ..
+    "RANGEINCL",  # ..=
     "COMMA",  # ,
     "QUOTES",  # '
     "DQUOTES",  # "
     "PIPE",
     "COLON",  # :
+    "SEMI",  # ;
     "COMMENT",  # //
     "WS",  # Whitespaces
     "NEWLINE",  # \n
@@ -53,6 +75,61 @@ tokens = (
     "EOF"
 )
 
+# How each token type is named in diagnostics
+DISPLAY_NAMES = {
+    "ID": "an identifier",
+    "INT": "an integer",
+    "FLOAT": "a float",
+    "STR": "a string",
+    "PLUS": "'+'",
+    "MINUS": "'-'",
+    "MULTIPLY": "'*'",
+    "DIVIDE": "'/'",
+    "MODULO": "'%'",
+    "AMPER": "'&'",
+    "NOT": "'not'",
+    "EQUALS": "'='",
+    "LT": "'<'",
+    "GT": "'>'",
+    "LTE": "'<='",
+    "GTE": "'>='",
+    "DEQ": "'=='",
+    "NE": "'!='",
+    "COALESCE": "'??'",
+    "LPAR": "'('",
+    "RPAR": "')'",
+    "LSQUARE": "'['",
+    "RSQUARE": "']'",
+    "LBRACE": "'{'",
+    "RBRACE": "'}'",
+    "AT": "'@'",
+    "DOT": "'.'",
+    "RANGE": "'..'",
+    "RANGEINCL": "'..='",
+    "COMMA": "','",
+    "QUOTES": "\"'\"",
+    "DQUOTES": "'\"'",
+    "PIPE": "'|'",
+    "COLON": "':'",
+    "SEMI": "';'",
+    "NEWLINE": "end of line",
+    "INDENT": "an indented block",
+    "DEDENT": "end of block",
+    "EOF": "end of file",
+}
+
+
+def describe(type, edition=None):
+    """How a token type reads in a diagnostic for `edition` (default: the
+    latest); a keyword the edition does not reserve is not named."""
+    keywords = KEYWORDS[edition or list(KEYWORDS)[-1]]
+    if type == "NOT" and "not" not in keywords:
+        return "'~'"
+    if type in DISPLAY_NAMES:
+        return DISPLAY_NAMES[type]
+    keyword = next((k for k, v in keywords.items() if v == type), None)
+    return f"'{keyword}'" if keyword else type
+
 t_PLUS = r"\+"
 t_MINUS = r"-"
 t_MULTIPLY = r"\*"
@@ -69,32 +146,37 @@ t_LTE = r"\<\="
 t_GTE = r"\>\="
 t_DEQ = r"\=\="
 t_NE = r"\!\="
+t_COALESCE = r"\?\?"
 
-t_LBRACE = r"\["
-t_RBRACE = r"\]"
 
 t_DOT = r"\."
+t_RANGE = r"\.\."
+t_RANGEINCL = r"\.\.\="
 t_AT = r"@"
 t_COMMA = r"\,"
 t_QUOTES = r"\'"
 t_DQUOTES = r'"'
 t_PIPE = r"\|"
 t_COLON = r":"
+t_SEMI = r";"
 
 
 # Identifier
 def t_ID(t):
     r"[a-zA-Z_][a-zA-Z_0-9]*"
-    t.type = reserved.get(t.value, "ID")
+    t.type = t.lexer.keywords.get(t.value, "ID")
     return t
 
 
 # Data Types
+INT_BITS = 64
+INT_MAX = 2 ** (INT_BITS - 1) - 1
+
 t_STR = r'"([^"\n]|(\\"))*"'
 
 
 def t_FLOAT(t):
-    r"(\d*\.\d+)|(\d+\.\d*)"
+    r"(\d*\.\d+)|(\d+\.(?!\.)\d*)"
     t.value = float(t.value)
     return t
 
@@ -102,6 +184,10 @@ def t_FLOAT(t):
 def t_INT(t):
     r"\d+"
     t.value = int(t.value)
+    if t.value > INT_MAX:
+        raise QuarkError(
+            "QK0001", f"Integer literal {t.value} out of range for int{INT_BITS}.", t
+        )
     return t
 
 
@@ -119,6 +205,31 @@ def t_RPAR(t):
     return t
 
 
+# Lists and dicts may also span lines, so they share the paren counter
+def t_LSQUARE(t):
+    r"\["
+    t.lexer.paren_count += 1
+    return t
+
+
+def t_RSQUARE(t):
+    r"\]"
+    t.lexer.paren_count -= 1
+    return t
+
+
+def t_LBRACE(t):
+    r"\{"
+    t.lexer.paren_count += 1
+    return t
+
+
+def t_RBRACE(t):
+    r"\}"
+    t.lexer.paren_count -= 1
+    return t
+
+
 # Misc
 def t_WS(t):
     r"[ ]+"
@@ -128,15 +239,15 @@ def t_WS(t):
 
 def t_newline(t):
     r"\n+"
-    t.lineno += len(t.value)
+    t.lexer.lineno += len(t.value)
     t.type = "NEWLINE"
     if t.lexer.paren_count == 0:
         return t
 
 
 def t_error(t):
-    print(f"Illegal Character: '{t.value[0]}'")
-    t.lexer.skip(1)
+    t.value = t.value[0]
+    raise QuarkError("QK0025", f"Illegal character '{t.value}'.", t)
 
 
 t_ignore_COMMENT = r"\//.*"
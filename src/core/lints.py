@@ -0,0 +1,365 @@
+from .helper_types import NodeType
+from .diagnostics import QuarkError, Suggestion
+from .session import LineIndex
+from . import effects, metrics
+
+N = NodeType
+
+# Diagnostic code reported by each lint in options.LINTS
+CODES = {
+    "unused-variable": "QK0015",
+    "shadowing": "QK0016",
+    "juxtaposition": "QK0017",
+    "deprecated": "QK0018",
+    "uninitialized": "QK0021",
+    "unused-assignment": "QK0022",
+    "discarded-result": "QK0023",
+    "complexity": "QK0024",
+}
+
+
+class Scope:
+    def __init__(self, parent=None):
+        self.parent = parent
+        # name -> token of its first binding in this scope
+        self.bindings = {}
+        self.reads = set()
+
+    def find(self, name):
+        scope = self
+        while scope and name not in scope.bindings:
+            scope = scope.parent
+        return scope
+
+
+class Linter:
+    """Walks a parsed tree once, collecting a finding per lint hit."""
+
+    def __init__(self, source):
+        self.source = source
+        self.lines = LineIndex(source)
+        self.findings = []
+
+    def warn(self, lint, message, tok, suggestions=None):
+        self.findings.append((lint, message, tok, suggestions))
+
+    def bind(self, scope, tok, local=True):
+        name = tok.value
+        if name in scope.bindings:
+            return
+        outer = scope.parent.find(name) if scope.parent else None
+        if local and outer:
+            line = self.line(outer.bindings[name])
+            self.warn("shadowing", f"'{name}' shadows the binding on line {line}.", tok)
+        scope.bindings[name] = tok
+
+    def line(self, tok):
+        return self.lines.line_col(tok.pos)[0]
+
+    def targets(self, node):
+        if node.type == N.Tuple:
+            for child in node.children:
+                yield from self.targets(child)
+        elif node.type == N.Identifier:
+            yield node.tok
+
+    def pure(self, node):
+        """Whether evaluating `node` has no effects; only calls can have any."""
+        calls = [n for n, _ in node.walk() if n.type == N.FunctionCall]
+        return all(map(self.analysis.pure_call, calls))
+
+    def visit(self, node, scope):
+        match node.type:
+            case N.CompilationUnit:
+                self.analysis = effects.analyze(node)
+                for child in node.children:
+                    self.visit(child, scope)
+            case N.Function:
+                name, params, body = node.children[:3]
+                self.bind(scope, name.tok, local=False)
+                inner = Scope(scope)
+                for param in params.children:
+                    self.bind(inner, param.tok)
+                params = set(inner.bindings)
+                self.visit(body, inner)
+                for child in node.children[3:]:
+                    self.visit(child, scope)
+                for name, tok in inner.bindings.items():
+                    if name not in inner.reads and name not in params and not name.startswith("_"):
+                        self.warn("unused-variable", f"'{name}' is assigned but never read.", tok)
+                Flow(self, inner, params).run(body)
+            case N.Assignment:
+                target, value = node.children
+                self.visit(value, scope)
+                for tok in self.targets(target):
+                    self.bind(scope, tok, local=scope.parent is not None)
+                if target.type == N.MemberAccess:
+                    self.visit(target, scope)
+            case N.Identifier:
+                owner = scope.find(node.tok.value)
+                if owner:
+                    owner.reads.add(node.tok.value)
+            case N.MemberAccess:
+                self.visit(node.children[0], scope)
+            case N.Use:
+                for child in node.children:
+                    # `use a.b` binds `b`
+                    while child.type == N.MemberAccess:
+                        child = child.children[-1]
+                    self.bind(scope, child.tok, local=False)
+            case N.Enum | N.Struct:
+                self.bind(scope, node.children[0].tok, local=False)
+            case N.Attribute:
+                for child in node.children:
+                    self.visit(child, scope)
+            case _:
+                if node.type == N.Block:
+                    self.juxtaposed(node)
+                for child in node.children:
+                    if child:
+                        self.visit(child, scope)
+
+    # A call statement whose value is neither returned nor stored; the last
+    # statement of a function body or branch may be its result
+    def discarded(self, tree):
+        analysis = self.analysis
+        for block, _ in tree.walk():
+            if block.type != N.Block:
+                continue
+            module = block is tree.children[0]
+            for stmt in block.children if module else block.children[:-1]:
+                if stmt.type == N.FunctionCall and analysis.pure_call(stmt):
+                    callee = stmt.children[0].tok
+                    self.warn(
+                        "discarded-result",
+                        f"'{callee.value}' has no effects, so discarding its result does nothing.",
+                        callee,
+                    )
+
+    # `f x` on one line is two statements; a call needs `@f x`
+    def juxtaposed(self, block):
+        warned = None
+        for first, after in zip(block.children, block.children[1:]):
+            first_tok, after_tok = start(first), start(after)
+            line = self.line(first_tok)
+            between = self.source[end(first) : after_tok.pos]
+            if line == warned or "\n" in between or ";" in between:
+                continue
+            # One warning per line is enough
+            warned = line
+            if first.type == N.Identifier:
+                self.warn(
+                    "juxtaposition",
+                    f"'{first.tok.value}' is followed by an expression on the same line; "
+                    "this is not a call.",
+                    first.tok,
+                    [Suggestion("write '@' to call it", first.tok.pos, first.tok.pos, "@", safe=False)],
+                )
+            else:
+                self.warn("juxtaposition", "Two statements on one line.", after_tok)
+
+
+class Flow:
+    """Path-sensitive checks on one function's locals. Quark has no loops,
+    so the only branching is an if-chain and walking the body in order (or
+    in reverse, for liveness) visits every path.
+
+    Module-level names are left alone: functions defined there may read
+    them at any later point."""
+
+    def __init__(self, linter, scope, params):
+        self.linter = linter
+        self.params = params
+        self.locals = set(scope.bindings) - params
+        self.read = scope.reads
+        self.warned = set()
+
+    def run(self, body):
+        self.body = body
+        # Names a nested function reads may be used whenever it is called
+        self.captured = {
+            n.tok.value
+            for fn, _ in body.walk()
+            if fn.type == N.Function
+            for n, _ in fn.children[2].walk()
+            if n.type == N.Identifier
+        }
+        self.forward(body, set(self.params), set(self.params))
+        self.backward(body, set())
+
+    # Uninitialized reads: `definite` holds names assigned on every path to
+    # this point, `possible` those assigned on at least one
+    def forward(self, block, definite, possible):
+        for stmt in block.children:
+            if stmt.type == N.Condition:
+                children = stmt.children
+                has_else = len(children) % 2 == 1
+                outs = []
+                for test, body in zip(children[0::2], children[1::2]):
+                    self.evaluate(test, definite, possible)
+                    outs.append(self.forward(body, set(definite), set(possible)))
+                if has_else:
+                    outs.append(self.forward(children[-1], set(definite), set(possible)))
+                else:
+                    outs.append((definite, possible))
+                definite = set.intersection(*(d for d, _ in outs))
+                possible = set.union(*(p for _, p in outs))
+            else:
+                self.evaluate(stmt, definite, possible)
+        return definite, possible
+
+    def evaluate(self, node, definite, possible):
+        match node.type:
+            case N.Assignment:
+                target, value = node.children
+                self.evaluate(value, definite, possible)
+                if target.type == N.MemberAccess:
+                    self.evaluate(target, definite, possible)
+                names = {tok.value for tok in self.linter.targets(target)}
+                definite |= names
+                possible |= names
+            case N.Identifier:
+                self.check(node.tok, definite, possible)
+            case N.MemberAccess:
+                self.evaluate(node.children[0], definite, possible)
+            case N.Function | N.Enum | N.Struct:
+                definite.add(node.children[0].tok.value)
+                possible.add(node.children[0].tok.value)
+            case N.Use:
+                pass
+            case _:
+                for child in node.children:
+                    if child:
+                        self.evaluate(child, definite, possible)
+
+    def check(self, tok, definite, possible):
+        name = tok.value
+        if name not in self.locals or name in definite or name in self.warned:
+            return
+        self.warned.add(name)
+        if name in possible:
+            message = f"'{name}' may be read before it is assigned."
+        else:
+            message = f"'{name}' is read before it is assigned."
+        self.linter.warn("uninitialized", message, tok, [self.initialize(name)])
+
+    def initialize(self, name):
+        # `;` works whether the body is inline or indented
+        first = start(self.body)
+        return Suggestion(
+            f"initialize '{name}' first", first.pos, first.pos, f"{name} = 0; ", safe=False
+        )
+
+    # Dead stores: `live` holds names whose current value may still be read
+    def backward(self, block, live):
+        for stmt in reversed(block.children):
+            if stmt.type == N.Condition:
+                children = stmt.children
+                has_else = len(children) % 2 == 1
+                after = live
+                live = self.backward(children[-1], set(after)) if has_else else after
+                for test, body in reversed(list(zip(children[0::2], children[1::2]))):
+                    live = self.backward(body, set(after)) | live | self.reads(test)
+            elif stmt.type == N.Assignment and stmt.children[0].type == N.Identifier:
+                target, value = stmt.children
+                self.store(stmt, block, live)
+                live = (live - {target.tok.value}) | self.reads(value)
+            else:
+                live = live | self.reads(stmt)
+        return live
+
+    def store(self, stmt, block, live):
+        target, value = stmt.children
+        name = target.tok.value
+        # Never-read names are already reported as unused-variable
+        if (
+            name in live
+            or name not in self.read
+            or name in self.captured
+            or name.startswith("_")
+        ):
+            return
+        lines = self.removable(stmt, block)
+        if lines:
+            fix = Suggestion("remove the assignment", *lines, "")
+        else:
+            # Keep the value, which may have effects or be the block's result
+            fix = Suggestion(
+                "remove the assignment", target.tok.pos, start(value).pos, "", safe=False
+            )
+        self.linter.warn(
+            "unused-assignment",
+            f"The value assigned to '{name}' is never read.",
+            target.tok,
+            [fix],
+        )
+
+    def removable(self, stmt, block):
+        """The span of the lines holding `stmt`, if the whole statement can
+        go: its value has no effects, it is not the last statement of its
+        block (which may be the block's result), and nothing else shares
+        its lines."""
+        if stmt is block.children[-1] or not self.linter.pure(stmt.children[1]):
+            return None
+        source, lines = self.linter.source, self.linter.lines
+        first, last = start(stmt).pos, end(stmt)
+        lo = lines.line_span(lines.line_col(first)[0])[0]
+        hi = lines.line_span(lines.line_col(last - 1)[0])[1]
+        if source[lo:first].strip() or source[last:hi].strip():
+            return None
+        return lo, min(hi + 1, len(source))
+
+    def reads(self, node):
+        names = set()
+        match node.type:
+            case N.Identifier:
+                names.add(node.tok.value)
+            case N.Assignment:
+                target, value = node.children
+                names |= self.reads(value)
+                if target.type == N.MemberAccess:
+                    names |= self.reads(target)
+            case N.MemberAccess:
+                names |= self.reads(node.children[0])
+            case N.Function | N.Use | N.Enum | N.Struct:
+                pass
+            case _:
+                for child in node.children:
+                    if child:
+                        names |= self.reads(child)
+        return names
+
+
+def start(node):
+    return min((n.tok for n, _ in node.walk() if n.tok), key=lambda tok: tok.pos)
+
+
+def end(node):
+    return max(n.tok.end for n, _ in node.walk() if n.tok)
+
+
+def run(tree, source, options, findings=()):
+    """Lint diagnostics for `tree` plus any `findings` from other passes, at
+    the severity `options` gives each lint."""
+    linter = Linter(source)
+    linter.visit(tree, Scope())
+    linter.discarded(tree)
+    for result, metric, value, limit in metrics.exceeded(
+        metrics.collect(tree, linter.lines), options.thresholds
+    ):
+        linter.warn(
+            "complexity",
+            f"'{result['name']}' has {metrics.PHRASES[metric].format(value)}; the limit is {limit}.",
+            result["tok"],
+        )
+
+    diagnostics = []
+    for lint, message, tok, suggestions in [*linter.findings, *findings]:
+        level = options.level(lint)
+        if level == "allow":
+            continue
+        severity = "error" if level == "deny" else "warning"
+        diagnostics.append(
+            QuarkError(CODES[lint], message, tok, severity=severity, suggestions=suggestions)
+        )
+    return sorted(diagnostics, key=lambda d: d.tok.pos)
@@ -0,0 +1,88 @@
+from .helper_types import NodeType
+
+N = NodeType
+
+# Each limit is compared against the metric of the same name; thresholds
+# come from the [metrics] table of quark.toml (see config.DEFAULTS)
+LIMITS = {
+    "max_complexity": "complexity",
+    "max_nesting": "nesting",
+    "max_params": "params",
+    "max_lines": "lines",
+}
+
+# How a value of each metric reads in a warning
+PHRASES = {
+    "complexity": "a complexity of {}",
+    "nesting": "ifs nested {} deep",
+    "params": "{} parameters",
+    "lines": "{} lines",
+}
+
+
+def _own(node):
+    """Nodes of a function body, not descending into nested functions."""
+    yield node
+    for child in node.children:
+        if child and child.type != N.Function:
+            yield from _own(child)
+
+
+def complexity(body):
+    """McCabe complexity: one plus a decision per `if`/`elseif` test. Quark
+    has no loops, and `and`/`or` are not yet operators, so these are the
+    only branches."""
+    return 1 + sum(len(n.children) // 2 for n in _own(body) if n.type == N.Condition)
+
+
+def nesting(node):
+    """Deepest if-chain nesting under `node`; a function with one `if` has
+    depth 1."""
+    depths = [0]
+    for child in node.children:
+        if child and child.type != N.Function:
+            depths.append(nesting(child) + (child.type == N.Condition))
+    return max(depths)
+
+
+def collect(tree, lines):
+    """Metrics for every function, nested ones included, in source order.
+    Names are qualified by enclosing functions, e.g. `outer.inner`."""
+    results = []
+
+    def visit(node, prefix):
+        if node.type == N.Function:
+            name = node.children[0].tok
+            qualified = f"{prefix}.{name.value}" if prefix else name.value
+            body = node.children[2]
+            toks = [t for n, _ in node.walk() if (t := n.tok)]
+            first = lines.line_col(min(t.pos for t in toks))[0]
+            last = lines.line_col(max(t.end for t in toks) - 1)[0]
+            results.append(
+                {
+                    "name": qualified,
+                    "tok": name,
+                    "line": first,
+                    "complexity": complexity(body),
+                    "nesting": nesting(body),
+                    "params": len(node.children[1].children),
+                    "lines": last - first + 1,
+                }
+            )
+            prefix = qualified
+        for child in node.children:
+            if child:
+                visit(child, prefix)
+
+    visit(tree, None)
+    return results
+
+
+def exceeded(results, thresholds):
+    """(function metrics, metric, value, limit) for each limit a function
+    goes over. A limit of 0 is off."""
+    for result in results:
+        for limit, metric in LIMITS.items():
+            value, bound = result[metric], thresholds.get(limit, 0)
+            if bound and value > bound:
+                yield result, metric, value, bound
@@ -0,0 +1,32 @@
+from .lex_grammar import KEYWORDS
+from .diagnostics import Suggestion
+from . import deprecations
+
+
+def plan(tokens, tree, edition, target):
+    """Rewrites that move a program from `edition` to `target`, as
+    (token, suggestion) pairs. `tokens` and `tree` come from `edition`."""
+    changes = []
+
+    # Names that became keywords get trailing underscores, as many as it
+    # takes not to collide with a name the program already uses
+    new_keywords = set(KEYWORDS[target]) - set(KEYWORDS[edition])
+    names = {tok.value for tok in tokens if tok.type == "ID"}
+    renames = {}
+    for keyword in sorted(new_keywords & names):
+        fresh = keyword + "_"
+        while fresh in names or fresh in renames.values():
+            fresh += "_"
+        renames[keyword] = fresh
+    for tok in tokens:
+        if tok.type == "ID" and tok.value in renames:
+            end = tok.pos + len(tok.value)
+            changes.append(
+                (tok, Suggestion(f"rename '{tok.value}', now a keyword", tok.pos, end, renames[tok.value]))
+            )
+
+    for deprecation in deprecations.active(target):
+        for tok, fix in deprecation.find(tokens, tree):
+            changes.append((tok, fix))
+
+    return sorted(changes, key=lambda change: change[0].pos)
@@ -0,0 +1,133 @@
+import argparse
+from dataclasses import dataclass, field
+from . import config
+
+EDITIONS = ["2024", "2025"]
+
+# Syntax that is still settling. Off unless enabled with --feature, so
+# changing it cannot break existing programs.
+EXPERIMENTAL = {
+    "sets": "set literals `{a, b}`",
+    "coalesce": "the `??` operator",
+    "attributes": "`@[...]` function attributes",
+}
+
+# Warnings, each on by default and controlled with -W/-A/-D <name>
+LINTS = {
+    "unused-variable": "a local variable that is assigned but never read",
+    "shadowing": "a local name that hides one from an enclosing scope",
+    "juxtaposition": "`f x` on one line, which is two statements rather than a call",
+    "deprecated": "syntax superseded in the current edition",
+    "uninitialized": "a local variable read before it is assigned on some path",
+    "unused-assignment": "a value stored in a local variable that is overwritten before any read",
+    "discarded-result": "a call to a function without effects whose result is thrown away",
+    "complexity": "a function over one of the [metrics] limits in quark.toml",
+}
+LEVELS = {"-W": "warn", "-A": "allow", "-D": "deny"}
+
+
+@dataclass(frozen=True)
+class LexerOptions:
+    edition: str = EDITIONS[-1]
+
+
+@dataclass(frozen=True)
+class ParserOptions:
+    features: frozenset = frozenset()
+    # The lexer's edition, for diagnostics that name keywords
+    edition: str = EDITIONS[-1]
+
+    def enabled(self, feature):
+        return feature in self.features
+
+
+@dataclass(frozen=True)
+class LintOptions:
+    # (lint, level) pairs in command-line order; later flags win
+    levels: tuple = ()
+    deny_warnings: bool = False
+    # The [metrics] config table, for the complexity lint
+    thresholds: dict = field(default_factory=dict)
+
+    def level(self, lint):
+        level = "warn"
+        for name, flag_level in self.levels:
+            if name == lint:
+                level = flag_level
+        return "deny" if level == "warn" and self.deny_warnings else level
+
+
+def add_arguments(argparser):
+    argparser.add_argument(
+        "--feature",
+        action="append",
+        default=[],
+        choices=sorted(EXPERIMENTAL),
+        help="enable experimental syntax",
+    )
+    argparser.add_argument(
+        "--edition", choices=EDITIONS, help="language edition (overrides quark.toml)"
+    )
+
+
+def add_lint_arguments(argparser):
+    for flag, level in LEVELS.items():
+        argparser.add_argument(
+            flag,
+            dest="lints",
+            action="append",
+            default=[],
+            type=lambda name, level=level: _lint_level(name, level),
+            metavar="LINT",
+            help=f"{level} a lint ({', '.join(LINTS)})",
+        )
+    argparser.add_argument(
+        "--deny-warnings", action="store_true", help="treat every warning as an error"
+    )
+
+
+def _lint_level(name, level):
+    if name not in LINTS:
+        raise argparse.ArgumentTypeError(
+            f"unknown lint '{name}', expected one of {', '.join(LINTS)}"
+        )
+    return name, level
+
+
+def lint_options(args):
+    settings = config.load(args.file)
+    return LintOptions(
+        levels=_config_levels(settings["lints"]) + tuple(args.lints),
+        deny_warnings=args.deny_warnings,
+        thresholds=settings["metrics"],
+    )
+
+
+def _config_levels(table):
+    """(lint, level) pairs from the [lints] config table."""
+    for name, level in table.items():
+        if name not in LINTS:
+            raise SystemExit(
+                f"Unknown lint '{name}' in [lints], expected one of {', '.join(LINTS)}."
+            )
+        if level not in LEVELS.values():
+            raise SystemExit(
+                f"Unknown level '{level}' for lint '{name}', expected allow, warn or deny."
+            )
+    return tuple(table.items())
+
+
+def from_args(args, edition=EDITIONS[-1]):
+    return ParserOptions(features=frozenset(args.feature), edition=edition)
+
+
+def lexer_options(args, path=None):
+    """The edition comes from --edition, else `edition` in the project config
+    of `path` (by default the driver's file)."""
+    start = path or args.file
+    settings = config.load(start, {"edition": args.edition} if args.edition else None)
+    if settings["edition"] not in EDITIONS:
+        raise SystemExit(
+            f"Unknown edition '{settings['edition']}', expected one of {', '.join(EDITIONS)}."
+        )
+    return LexerOptions(edition=settings["edition"])
@@ -0,0 +1,74 @@
+from enum import Enum
+from dataclasses import dataclass
+from .helper_types import NodeType, Precedence
+
+
+class Assoc(Enum):
+    Left = "left"
+    Right = "right"
+    # Chaining is an error, e.g. `a = b = c`
+    NonAssoc = "none"
+
+
+@dataclass(frozen=True)
+class Binding:
+    precedence: int
+    assoc: Assoc
+    kind: NodeType
+
+    def right_precedence(self):
+        """Minimum precedence of the operand parsed to the right."""
+        return self.precedence + (0 if self.assoc == Assoc.Right else 1)
+
+
+N, P = NodeType, Precedence
+
+# Operators between two operands. The Pratt loop in ExprParser.parse keeps
+# extending the left operand while the next token's precedence is at least
+# the current minimum.
+INFIX = {
+    "EQUALS": Binding(P.Assignment, Assoc.NonAssoc, N.Assignment),
+    "COALESCE": Binding(P.Coalesce, Assoc.Right, N.Operator),
+    "DEQ": Binding(P.Equality, Assoc.Left, N.Operator),
+    "NE": Binding(P.Equality, Assoc.Left, N.Operator),
+    "IS": Binding(P.Equality, Assoc.Left, N.Operator),
+    "LT": Binding(P.Comparison, Assoc.Left, N.Operator),
+    "GT": Binding(P.Comparison, Assoc.Left, N.Operator),
+    "LTE": Binding(P.Comparison, Assoc.Left, N.Operator),
+    "GTE": Binding(P.Comparison, Assoc.Left, N.Operator),
+    "RANGE": Binding(P.Range, Assoc.NonAssoc, N.Range),
+    "RANGEINCL": Binding(P.Range, Assoc.NonAssoc, N.Range),
+    "PLUS": Binding(P.Term, Assoc.Left, N.Operator),
+    "MINUS": Binding(P.Term, Assoc.Left, N.Operator),
+    "MULTIPLY": Binding(P.Factor, Assoc.Left, N.Operator),
+    "DIVIDE": Binding(P.Factor, Assoc.Left, N.Operator),
+    "DOT": Binding(P.Member, Assoc.Left, N.MemberAccess),
+}
+
+# Operators before their operand; `precedence` is how much of the
+# following expression they take
+PREFIX = {
+    "NOT": Binding(P.Not, Assoc.Right, N.Operator),
+    "RANGE": Binding(P.Range, Assoc.NonAssoc, N.Range),
+    "RANGEINCL": Binding(P.Range, Assoc.NonAssoc, N.Range),
+    "MINUS": Binding(P.Unary, Assoc.Right, N.Operator),
+}
+
+
+def precedence_name(level):
+    return next(k for k, v in vars(Precedence).items() if v == level)
+
+
+def rows():
+    """(position, token, precedence, associativity, node) for each operator,
+    loosest binding first."""
+    table = [("prefix", t, b) for t, b in PREFIX.items()]
+    table += [("infix", t, b) for t, b in INFIX.items()]
+    for position, type, binding in sorted(table, key=lambda row: row[2].precedence):
+        yield (
+            position,
+            type,
+            precedence_name(binding.precedence),
+            binding.assoc.value,
+            str(binding.kind),
+        )
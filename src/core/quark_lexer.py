@@ -1,4 +1,10 @@
 from ply import lex
+from .lex_grammar import KEYWORDS
+from .options import LexerOptions
+from .diagnostics import QuarkError, Suggestion
+
+# Keywords whose line usually ends in ':' to open an indented block
+BLOCK_OPENERS = ["IF", "ELSEIF", "ELSE", "FN", "FOR", "WHILE"]
 
 
 class QuarkLexer:
@@ -9,6 +15,9 @@ class QuarkLexer:
     def _new_token(self, type, lineno, pos):
         tok = lex.Token()
         tok.type, tok.value, tok.lineno, tok.pos = type, None, lineno, pos
+        # Synthetic tokens span no text
+        tok.end = pos
+        tok.synthetic = True
         return tok
 
     def _track_tokens_filter(self, tokens):
@@ -53,6 +62,8 @@ class QuarkLexer:
         token = None
         depth = 0
         prev_was_ws = False
+        # End of the previous line, and whether it looked like a block opener
+        newline, opener, line_opener = None, False, False
         for token in tokens:
             # WS only occurs at the start of the line
             # There may be WS followed by NEWLINE so
@@ -71,6 +82,7 @@ class QuarkLexer:
                     # ignore blank lines
                     continue
                 # pass the other cases on through
+                newline, opener, line_opener = token, line_opener, False
                 yield token
                 continue
 
@@ -78,10 +90,24 @@ class QuarkLexer:
             # which can affect the indentation level
 
             prev_was_ws = False
+            line_opener = line_opener or token.type in BLOCK_OPENERS
             if token.must_indent:
                 # The current depth must be larger than the previous level
                 if not (depth > levels[-1]):
-                    raise IndentationError("expected an indented block")
+                    suggestions = []
+                    if newline:
+                        suggestions.append(
+                            Suggestion(
+                                "write 'pass' for an empty body",
+                                newline.pos,
+                                newline.pos,
+                                " pass",
+                                safe=False,
+                            )
+                        )
+                    raise QuarkError(
+                        "QK0002", "expected an indented block", token, suggestions=suggestions
+                    )
 
                 levels.append(depth)
                 yield self._new_token("INDENT", token.lineno, token.pos)
@@ -92,13 +118,29 @@ class QuarkLexer:
                     # At the same level
                     pass
                 elif depth > levels[-1]:
-                    raise IndentationError("indentation increase but not in new block")
+                    suggestions = []
+                    if newline:
+                        suggestions.append(
+                            Suggestion(
+                                "add ':' to start a block on the previous line",
+                                newline.pos,
+                                newline.pos,
+                                ":",
+                                safe=opener,
+                            )
+                        )
+                    raise QuarkError(
+                        "QK0003",
+                        "indentation increase but not in new block",
+                        token,
+                        suggestions=suggestions,
+                    )
                 else:
                     # Back up; but only if it matches a previous level
                     try:
                         i = levels.index(depth)
                     except ValueError:
-                        raise IndentationError("inconsistent indentation")
+                        raise QuarkError("QK0004", "inconsistent indentation", token)
                     for _ in range(i + 1, len(levels)):
                         yield self._new_token("DEDENT", token.lineno, token.pos)
                         levels.pop()
@@ -120,11 +162,13 @@ class QuarkLexer:
 
         if add_endmarker:
             yield self._new_token(
-                "EOF", *(token.lineno, token.pos) if token else (1, 0)
+                "EOF", token.lineno if token else 1, self.lexer.lexlen
             )
 
-    def input(self, source, add_endmarker=True):
+    def input(self, source, add_endmarker=True, options=LexerOptions()):
+        self.lexer.keywords = KEYWORDS[options.edition]
         self.lexer.paren_count = 0
+        self.lexer.lineno = 1
         self.lexer.input(source)
         self.token_stream = self._indent_filter(add_endmarker)
 
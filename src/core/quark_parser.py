@@ -1,23 +1,60 @@
 from core.expr_parser import ExprParser
-from .helper_types import NodeType, TreeNode
+from .diagnostics import QuarkError, Suggestion
+from .options import ParserOptions, EXPERIMENTAL
+from .validator import validate
+from .helper_types import NodeType, Precedence, TreeNode, assign_ids
+from .lex_grammar import KEYWORDS, describe
+
+# Tokens with fixed text that can be inserted as a quick fix
+INSERTABLE = {
+    "COLON": ":",
+    "RPAR": ")",
+    "RSQUARE": "]",
+    "RBRACE": "}",
+    "COMMA": ",",
+}
 
 
 class QuarkParser:
-    def __init__(self, token_stream):
+    def __init__(self, token_stream, debug=True, options=ParserOptions()):
         self.tree = None
+        self.debug = debug
+        self.options = options
+        # Names bound by `use` statements so far, to catch conflicting aliases
+        self.imports = {}
         self.tokens = list(token_stream)
         self.expr_parser = ExprParser(self)
         self.prev, self.cur = None, self.tokens[0]
 
     # Util functions
+    def log(self, msg):
+        if self.debug:
+            print(msg)
+
     def peek(self, index=1):
-        return self.tokens[index] if index < len(self.tokens) else None
+        # Never look past the trailing EOF token
+        return self.tokens[min(index, len(self.tokens) - 1)]
 
     def consume(self):
+        if len(self.tokens) == 1:
+            raise QuarkError("QK0005", "Unexpected end of input.", self.cur)
+
         self.prev = self.tokens.pop(0)
         self.cur = self.tokens[0]
         return self.prev
 
+    def require(self, feature, tok):
+        if not self.options.enabled(feature):
+            raise QuarkError(
+                "QK0012",
+                f"Experimental syntax ({EXPERIMENTAL[feature]}) is disabled; "
+                f"enable it with --feature {feature}.",
+                tok,
+            )
+
+    def describe(self, type):
+        return describe(type, self.options.edition)
+
     def is_term(self, token):
         return token.type in ["ID", "INT", "FLOAT", "STR"]
 
@@ -25,50 +62,126 @@ class QuarkParser:
         if self.cur.type == type:
             return self.consume()
         else:
-            raise Exception(f"Expected {type} but got {self.cur.type}.")
+            suggestions = []
+            if type in INSERTABLE:
+                text = INSERTABLE[type]
+                # Insert right after the last token read; INDENT, DEDENT and
+                # EOF carry the position of a neighbouring token, so an
+                # insertion anchored on one may land in the wrong place
+                anchor = self.prev or self.cur
+                at = anchor.end if self.prev else anchor.pos
+                suggestions.append(
+                    Suggestion(
+                        f"insert '{text}' here",
+                        at,
+                        at,
+                        text,
+                        safe=not getattr(anchor, "synthetic", False) and self.cur.type != "EOF",
+                    )
+                )
+            raise QuarkError(
+                "QK0006",
+                f"Expected {self.describe(type)}, found {self.describe(self.cur.type)}.",
+                self.cur,
+                suggestions=suggestions,
+            )
 
     # Parsing functions
     def block(self):
-        print(f"Block: {self.cur}")
+        self.log(f"Block: {self.cur}")
         node = TreeNode(NodeType.Block)
 
         if self.cur.type == "NEWLINE" and self.peek().type == "INDENT":
-            pass
+            self.consume()
+            self.consume()
+            self.statements(node, "DEDENT")
+            self.expect("DEDENT")
+        elif self.cur.type in ["NEWLINE", "EOF"]:
+            raise QuarkError(
+                "QK0002",
+                "Expected a body after ':'; write 'pass' for an empty one.",
+                self.prev,
+                suggestions=[
+                    Suggestion("add 'pass'", self.prev.end, self.prev.end, " pass", safe=False)
+                ],
+            )
         else:
-            while self.cur.type != "NEWLINE":
+            while self.cur.type not in ["NEWLINE", "EOF"]:
                 node.children.append(self.statement())
-            self.expect("NEWLINE")
+                self.separator()
+            if self.cur.type != "EOF":
+                self.expect("NEWLINE")
 
         return node
 
+    # Like an inline block, a line may hold several statements side by side;
+    # the juxtaposition lint warns about those
+    def statements(self, node, end):
+        while self.cur.type not in [end, "EOF"]:
+            if self.cur.type == "NEWLINE":
+                self.consume()
+                continue
+            node.children.append(self.statement())
+            self.separator()
+
+    # `;` separates statements on one line and may also end the line
+    def separator(self):
+        if self.cur.type == "SEMI":
+            self.consume()
+
     def statement(self):
-        print(f"Statement: {self.cur}")
+        self.log(f"Statement: {self.cur}")
         node = None
 
         if self.cur.type == "IF":
             node = self.ifelse()
-        elif "FN" in [self.cur.type, self.peek(2).type]:
+        elif self.cur.type == "FN" or [self.peek().type, self.peek(2).type] == ["EQUALS", "FN"]:
             node = self.function()
+        elif self.cur.type == "AT" and self.peek().type == "LSQUARE":
+            node = self.attributed()
         elif self.cur.type == "AT":
             self.consume()
             node = self.function_call()
+        elif self.cur.type == "USE":
+            node = self.use()
+        elif self.cur.type in ["ENUM", "STRUCT"]:
+            node = self.declaration()
+        elif self.cur.type in ["PASS", "TODO"]:
+            # `todo` marks a body still to be written; codegen may trap on it
+            node = TreeNode(NodeType.Pass, self.consume())
         else:
-            node = self.expression()
+            node = self.assignment()
 
         return node
 
     def expression(self):
-        print(f"Expression: {self.cur}")
+        self.log(f"Expression: {self.cur}")
         return self.expr_parser.parse()
 
+    # An expression statement, or an assignment when '=' follows; '=' is only
+    # parsed here, so it cannot nest inside an expression or chain
+    def assignment(self):
+        self.log(f"Assignment: {self.cur}")
+        target = self.expr_parser.parse(precedence=Precedence.Assignment + 1)
+        if self.cur.type != "EQUALS":
+            return target
+
+        node = TreeNode(NodeType.Assignment, self.consume())
+        if not self.expr_parser.is_target(target):
+            raise QuarkError("QK0008", f"Cannot assign to {target}.", node.tok)
+        node.children.extend([target, self.expr_parser.parse(precedence=Precedence.Assignment + 1)])
+        if self.cur.type == "EQUALS":
+            raise QuarkError("QK0008", "Chained assignment is not supported.", self.cur)
+        return node
+
     def function(self):
-        print(f"Function: {self.cur}")
+        self.log(f"Function: {self.cur}")
         node = None
 
         if self.cur.type == "FN":
             node = TreeNode(NodeType.Function, self.consume())
             node.children.extend(
-                [TreeNode(NodeType.Identifier, self.expect("ID")), self.arguments()]
+                [TreeNode(NodeType.Identifier, self.expect("ID")), self.parameters()]
             )
             self.expect("COLON")
             node.children.append(self.block())
@@ -76,14 +189,116 @@ class QuarkParser:
             id = TreeNode(NodeType.Identifier, self.expect("ID"))
             self.expect("EQUALS")
             node = TreeNode(NodeType.Function, self.consume())
-            node.children.extend([id, self.arguments()])
+            node.children.extend([id, self.parameters()])
             self.expect("COLON")
             node.children.append(self.block())
 
         return node
 
+    def names(self):
+        names = [TreeNode(NodeType.Identifier, self.expect("ID"))]
+        while self.cur.type == "COMMA":
+            self.consume()
+            if self.cur.type in ["NEWLINE", "EOF"]:
+                break
+            names.append(TreeNode(NodeType.Identifier, self.expect("ID")))
+        return names
+
+    # Enum and Struct children are the type's name followed by its
+    # variants or fields respectively
+    def declaration(self):
+        self.log(f"Declaration: {self.cur}")
+        kind = NodeType.Enum if self.cur.type == "ENUM" else NodeType.Struct
+        node = TreeNode(kind, self.consume())
+        node.children.append(TreeNode(NodeType.Identifier, self.expect("ID")))
+        self.expect("COLON")
+        node.children.extend(self.names())
+        return node
+
+    # `@[name, name(args)]` lines before a function; the Attributes node is
+    # appended as the Function's last child
+    def attributed(self):
+        self.log(f"Attributes: {self.cur}")
+        self.require("attributes", self.cur)
+        attrs = TreeNode(NodeType.Attributes, self.cur)
+        while self.cur.type == "AT":
+            self.consume()
+            self.expect("LSQUARE")
+            while True:
+                attr = TreeNode(NodeType.Attribute, self.expect("ID"))
+                if self.cur.type == "LPAR":
+                    self.consume()
+                    while self.cur.type != "RPAR":
+                        attr.children.append(self.expression())
+                        if self.cur.type != "COMMA":
+                            break
+                        self.consume()
+                    self.expect("RPAR")
+                attrs.children.append(attr)
+
+                if self.cur.type != "COMMA":
+                    break
+                self.consume()
+            self.expect("RSQUARE")
+            if self.cur.type == "NEWLINE":
+                self.consume()
+
+        node = self.function()
+        if not node:
+            raise QuarkError("QK0010", "Attributes must precede a function.", self.cur)
+        node.children.append(attrs)
+        return node
+
+    def module_path(self):
+        node = TreeNode(NodeType.Identifier, self.expect("ID"))
+        while self.cur.type == "DOT":
+            node = TreeNode(NodeType.MemberAccess, self.consume(), [node])
+            node.children.append(TreeNode(NodeType.Identifier, self.expect("ID")))
+        return node
+
+    # `name as alias` becomes Alias[alias] wrapping the original name
+    def aliased(self, name):
+        if self.cur.type != "AS":
+            return name
+        self.consume()
+        return TreeNode(NodeType.Alias, self.expect("ID"), [name])
+
+    def bind_import(self, node):
+        while node.type == NodeType.MemberAccess:
+            node = node.children[-1]
+        name = node.tok.value
+        if name in self.imports:
+            raise QuarkError(
+                "QK0011",
+                f"'{name}' is already imported on line {self.imports[name].lineno}.",
+                node.tok,
+            )
+        self.imports[name] = node.tok
+
+    # Use children: the module (possibly aliased), then any selected names
+    def use(self):
+        self.log(f"Use: {self.cur}")
+        node = TreeNode(NodeType.Use, self.consume())
+        node.children.append(self.aliased(self.module_path()))
+
+        if self.cur.type != "COLON":
+            self.bind_import(node.children[0])
+            return node
+
+        self.consume()
+        while True:
+            name = self.aliased(TreeNode(NodeType.Identifier, self.expect("ID")))
+            self.bind_import(name)
+            node.children.append(name)
+            if self.cur.type != "COMMA":
+                break
+            self.consume()
+            if self.cur.type in ["NEWLINE", "EOF"]:
+                break
+        return node
+
     def function_call(self):
-        print(f"Function Call: {self.cur}")
+        self.log(f"Function Call: {self.cur}")
         node = TreeNode(NodeType.FunctionCall)
         node.children.extend(
             [TreeNode(NodeType.Identifier, self.expect("ID")), self.arguments()]
@@ -91,20 +306,82 @@ class QuarkParser:
         return node
 
     def arguments(self):
-        print(f"Arguments: {self.cur}")
+        self.log(f"Arguments: {self.cur}")
         node = TreeNode(NodeType.Arguments)
 
-        while self.cur.type not in ["COLON", "NEWLINE"]:
+        # Arguments are comma separated; a trailing comma is allowed
+        while self.cur.type not in ["COLON", "NEWLINE", "EOF"]:
             node.children.append(self.expression())
 
-            if self.cur.type == "COMMA":
-                self.consume()
+            if self.cur.type != "COMMA":
+                break
+            self.consume()
+
+        self.log(node)
+        return node
+
+    def parameters(self):
+        self.log(f"Parameters: {self.cur}")
+        node = TreeNode(NodeType.Arguments)
+
+        # Like arguments, but each parameter is a plain name
+        while self.cur.type not in ["COLON", "NEWLINE", "EOF"]:
+            node.children.append(TreeNode(NodeType.Identifier, self.expect("ID")))
+
+            if self.cur.type != "COMMA":
+                break
+            self.consume()
 
-        print(node)
+        self.log(node)
         return node
 
+    def misspelled_elseif(self, start, end):
+        # The 2024 edition spells it `elif`
+        keyword = "elseif" if "elseif" in KEYWORDS[self.options.edition] else "elif"
+        raise QuarkError(
+            "QK0014",
+            f"Use '{keyword}' for another branch.",
+            start,
+            suggestions=[Suggestion(f"write '{keyword}'", start.pos, end, keyword)],
+        )
+
+    # Condition children alternate test and Block for `if` and each
+    # `elseif`, then an optional `else` Block
     def ifelse(self):
-        pass
+        self.log(f"If: {self.cur}")
+        node = TreeNode(NodeType.Condition, self.consume())
+        node.children.extend(self.branch())
+
+        while True:
+            # `elif` is the 2024 spelling, deprecated from 2025 on
+            if self.cur.type in ["ELSEIF", "ELIF"]:
+                self.consume()
+                node.children.extend(self.branch())
+            elif self.cur.type == "ELSE" and self.peek().type == "IF":
+                self.misspelled_elseif(self.cur, self.peek().pos + len("if"))
+            elif self.cur.type == "ELSE":
+                self.consume()
+                self.expect("COLON")
+                node.children.append(self.block())
+                break
+            else:
+                break
+
+        return node
+
+    def branch(self):
+        test = self.assignment()
+        if test.type == NodeType.Assignment:
+            raise QuarkError(
+                "QK0013",
+                "Expected a condition, found an assignment.",
+                test.tok,
+                suggestions=[
+                    Suggestion("use '==' to compare", test.tok.pos, test.tok.pos + 1, "==")
+                ],
+            )
+        self.expect("COLON")
+        return [test, self.block()]
 
     def term(self):
         return TreeNode(
@@ -113,5 +390,10 @@ class QuarkParser:
         )
 
     def parse(self):
-        self.tree = TreeNode(NodeType.CompilationUnit)
-        self.tree.children.append(self.block())
+        self.tree = TreeNode(NodeType.CompilationUnit, children=[TreeNode(NodeType.Block)])
+        self.statements(self.tree.children[0], "EOF")
+        assign_ids(self.tree)
+
+        if __debug__:
+            errors = validate(self.tree)
+            assert not errors, "Parser produced a malformed tree:\n" + "\n".join(errors)
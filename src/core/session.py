@@ -0,0 +1,78 @@
+import bisect
+import unicodedata
+from dataclasses import dataclass, field
+
+TAB_WIDTH = 4
+
+
+def char_width(c):
+    """Terminal columns taken by `c`; tabs are handled by the caller."""
+    if unicodedata.combining(c):
+        return 0
+    return 2 if unicodedata.east_asian_width(c) in "WF" else 1
+
+
+class LineIndex:
+    """Offsets at which each line starts, for mapping offsets to lines and
+    columns (and back) in O(log n)."""
+
+    def __init__(self, text):
+        self.starts = [0] + [i + 1 for i, c in enumerate(text) if c == "\n"]
+        self.length = len(text)
+
+    def line_col(self, pos):
+        """Maps an offset to a 1-based (line, column) pair."""
+        line = bisect.bisect_right(self.starts, pos)
+        return line, pos - self.starts[line - 1] + 1
+
+    def offset(self, line, col):
+        """Inverse of line_col."""
+        return self.starts[line - 1] + col - 1
+
+    def line_span(self, line):
+        """(start, end) offsets of `line`, excluding its newline."""
+        end = self.starts[line] - 1 if line < len(self.starts) else self.length
+        return self.starts[line - 1], end
+
+
+@dataclass
+class SourceFile:
+    path: str
+    contents: str
+    lines: LineIndex = field(init=False, repr=False)
+
+    def __post_init__(self):
+        self.lines = LineIndex(self.contents)
+
+    def line_col(self, pos):
+        return self.lines.line_col(pos)
+
+    def line_text(self, line):
+        return self.contents[slice(*self.lines.line_span(line))]
+
+    def display_col(self, pos):
+        """0-based terminal column of offset `pos`, counting wide characters
+        as two columns and expanding tabs."""
+        line, _ = self.line_col(pos)
+        col = 0
+        for c in self.contents[self.lines.line_span(line)[0] : pos]:
+            col = col + TAB_WIDTH - col % TAB_WIDTH if c == "\t" else col + char_width(c)
+        return col
+
+
+class Session:
+    """Owns every source file loaded during a compilation; FileIds index into it."""
+
+    def __init__(self):
+        self.files = []
+
+    def add(self, path, contents):
+        self.files.append(SourceFile(path, contents))
+        return len(self.files) - 1
+
+    def load(self, path):
+        with open(path, "r") as inputf:
+            return self.add(path, inputf.read())
+
+    def __getitem__(self, file_id):
+        return self.files[file_id]
@@ -0,0 +1,134 @@
+from .helper_types import NodeType, TreeNode
+
+N = NodeType
+
+# Node types allowed to appear as an expression
+EXPRESSIONS = {
+    N.Identifier,
+    N.Literal,
+    N.Operator,
+    N.Range,
+    N.List,
+    N.Dict,
+    N.Tuple,
+    N.Set,
+    N.MemberAccess,
+    N.Function,
+    N.FunctionCall,
+}
+
+
+def _types(node):
+    return [c.type if c else None for c in node.children]
+
+
+def _check(node):
+    """Yields a message for every structural invariant `node` breaks."""
+    strays = [c for c in node.children if c is not None and not isinstance(c, TreeNode)]
+    if strays:
+        yield f"has a child that is not a tree node: {strays[0]!r}"
+        return
+
+    kids, types = node.children, _types(node)
+    if None in types:
+        yield "has a missing child"
+        return
+
+    match node.type:
+        case N.CompilationUnit:
+            if types != [N.Block]:
+                yield "must contain exactly one Block"
+        case N.Function:
+            if types[:3] != [N.Identifier, N.Arguments, N.Block] or types[3:] not in (
+                [],
+                [N.Attributes],
+            ):
+                yield "must have Identifier, Arguments and Block children, then optional Attributes"
+            elif any(t != N.Identifier for t in _types(kids[1])):
+                yield "parameters must be identifiers"
+        case N.Condition:
+            branches, rest = types[: len(types) // 2 * 2], types[len(types) // 2 * 2 :]
+            if (
+                not branches
+                or any(t not in EXPRESSIONS for t in branches[0::2])
+                or any(t != N.Block for t in branches[1::2] + rest)
+            ):
+                yield "must alternate tests and Blocks, then an optional else Block"
+        case N.FunctionCall:
+            if types != [N.Identifier, N.Arguments]:
+                yield "must have Identifier and Arguments children"
+        case N.Operator:
+            if len(kids) not in (1, 2):
+                yield f"must have 1 or 2 operands, found {len(kids)}"
+        case N.Assignment:
+            if len(kids) != 2:
+                yield "must have a target and a value"
+            elif not _is_target(kids[0]):
+                yield f"cannot assign to {kids[0]}"
+        case N.Range:
+            if len(types) != 2 or types == [N.Unbounded, N.Unbounded]:
+                yield "must have two bounds, at most one of them Unbounded"
+            elif any(t not in EXPRESSIONS | {N.Unbounded} for t in types):
+                yield "bounds must be expressions or Unbounded"
+            elif node.tok and node.tok.type == "RANGEINCL" and types[1] == N.Unbounded:
+                yield "inclusive range needs an end bound"
+        case N.Dict:
+            if len(kids) % 2:
+                yield "must alternate keys and values"
+        case N.Set:
+            if not kids:
+                yield "must have at least one element"
+        case N.Enum | N.Struct:
+            if len(kids) < 2 or any(t != N.Identifier for t in types):
+                yield "must have a name and at least one member, all identifiers"
+        case N.MemberAccess:
+            if len(kids) != 2 or types[1] != N.Identifier:
+                yield "must have an object and a member Identifier"
+        case N.Attributes:
+            if not kids or any(t != N.Attribute for t in types):
+                yield "must contain one or more Attribute nodes"
+        case N.Use:
+            if not kids:
+                yield "must name a module"
+        case N.Alias:
+            if len(kids) != 1:
+                yield "must wrap exactly one name"
+        case N.Pass | N.Unbounded:
+            if kids:
+                yield "must be a leaf"
+        case N.Identifier | N.Literal:
+            if kids:
+                yield "must be a leaf"
+            if not node.tok:
+                yield "must carry a token"
+
+    if node.type in [N.Operator, N.Assignment, N.Arguments, N.List, N.Tuple, N.Set, N.Dict]:
+        for child in kids:
+            if child and child.type not in EXPRESSIONS:
+                yield f"has non-expression child {child}"
+
+
+def _is_target(node):
+    if node.type == N.Tuple:
+        return len(node.children) > 0 and all(map(_is_target, node.children))
+    return node.type in [N.Identifier, N.MemberAccess]
+
+
+def _walk(node, path=()):
+    # TreeNode.walk, but stopping at children that are not nodes, which
+    # _check reports
+    path = path + (node,)
+    yield node, path
+    for child in node.children:
+        if isinstance(child, TreeNode):
+            yield from _walk(child, path)
+
+
+def validate(tree):
+    """Checks structural invariants over the whole tree, returning error messages."""
+    errors = []
+    for node, path in _walk(tree):
+        loc = f" at pos {node.tok.pos}" if node.tok else ""
+        where = " > ".join(str(n.type) for n in path)
+        errors.extend(f"{where}{loc}: {msg}" for msg in _check(node))
+    return errors
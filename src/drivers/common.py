@@ -0,0 +1,92 @@
+import os
+import argparse
+import contextlib
+from dataclasses import dataclass
+import ply.lex as lex
+from core.lex_grammar import *
+from core.quark_lexer import QuarkLexer
+from core import options, config
+from core.options import LexerOptions
+from core.quark_parser import QuarkParser
+from core.session import Session, SourceFile
+from core.diagnostics import QuarkError, report
+
+# Shared by every driver; ply builds it from the lex_grammar names above
+lexer = QuarkLexer(lex.lex())
+
+
+@dataclass
+class Parsed:
+    source: SourceFile
+    tokens: list
+    tree: object
+
+
+def argument_parser():
+    """An ArgumentParser with the options every driver takes: the message
+    format, --feature and --edition. Drivers add their own file argument."""
+    argparser = argparse.ArgumentParser()
+    argparser.add_argument("--message-format", choices=["human", "json"], default="human")
+    options.add_arguments(argparser)
+    return argparser
+
+
+def project_root(path):
+    """The quark.toml directory above `path`, else `path` itself (or its
+    directory, for a file). Module names in `use` are relative to it."""
+    project = config.find_project_config(path)
+    if project:
+        return os.path.dirname(project)
+    path = os.path.abspath(path)
+    return path if os.path.isdir(path) else os.path.dirname(path)
+
+
+def tokenize(text, args, path=None, edition=None):
+    """Tokens of `text`, lexed with the edition from `edition`, else --edition
+    or the project config of `path` (default: the driver's file)."""
+    lexer.input(text, options=_lexer_options(args, path, edition))
+    return list(lexer.token_stream)
+
+
+def _lexer_options(args, path, edition):
+    return LexerOptions(edition) if edition else options.lexer_options(args, path)
+
+
+def parse(text, args, path=None, edition=None, debug=False):
+    """(tokens, tree) for `text`; raises QuarkError."""
+    edition = _lexer_options(args, path, edition).edition
+    tokens = tokenize(text, args, path, edition)
+    parser = QuarkParser(tokens, debug=debug, options=options.from_args(args, edition))
+    parser.parse()
+    return tokens, parser.tree
+
+
+@contextlib.contextmanager
+def reported(source, args):
+    """Reports a QuarkError raised in the block and exits with status 1."""
+    try:
+        yield
+    except QuarkError as err:
+        report(err, source, args.message_format)
+        raise SystemExit(1)
+
+
+def load(path, args, session=None, debug=False):
+    """The parsed file at `path`, or None after reporting why it does not
+    parse; for drivers that go on to other files."""
+    session = session or Session()
+    source = session[session.load(path)]
+    try:
+        tokens, tree = parse(source.contents, args, path, debug=debug)
+    except QuarkError as err:
+        report(err, source, args.message_format)
+        return None
+    return Parsed(source, tokens, tree)
+
+
+def parse_file(args, debug=False):
+    """The driver's file, parsed; exits with status 1 if it does not parse."""
+    parsed = load(args.file, args, debug=debug)
+    if not parsed:
+        raise SystemExit(1)
+    return parsed
@@ -0,0 +1,11 @@
+from utils import callgraph
+from drivers.common import argument_parser, parse_file
+
+if __name__ == "__main__":
+    argparser = argument_parser()
+    argparser.add_argument("file")
+    argparser.add_argument("--format", choices=["dot", "json"], default="dot")
+    args = argparser.parse_args()
+
+    graph = callgraph.build(parse_file(args).tree)
+    print(callgraph.to_dot(graph) if args.format == "dot" else callgraph.to_json(graph))
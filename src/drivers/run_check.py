@@ -0,0 +1,60 @@
+from core import options
+from core.session import Session
+from core import lints, deprecations
+from core.diagnostics import QuarkError, report, apply_suggestions
+from drivers.common import argument_parser, parse
+
+# Upper bound on fix/reparse rounds so a bad suggestion cannot loop forever
+MAX_FIX_ROUNDS = 16
+
+
+def check(source, args, lint_options):
+    """A parse error, or else any lint diagnostics."""
+    try:
+        tokens, tree = parse(source, args)
+    except QuarkError as err:
+        return [err]
+    findings = deprecations.find(tokens, tree, options.lexer_options(args).edition)
+    return lints.run(tree, source, lint_options, findings)
+
+
+def fix(source, args, lint_options):
+    """Applies safe suggestions until none are left, returning the fixed
+    text and the diagnostics that remain for it."""
+    diagnostics = check(source, args, lint_options)
+    fixed = source
+    for _ in range(MAX_FIX_ROUNDS):
+        safe = [s for d in diagnostics for s in d.suggestions if s.safe]
+        if not safe:
+            break
+        fixed = apply_suggestions(fixed, safe)
+        diagnostics = check(fixed, args, lint_options)
+    return fixed, diagnostics
+
+
+if __name__ == "__main__":
+    argparser = argument_parser()
+    argparser.add_argument("file")
+    argparser.add_argument("--fix", action="store_true")
+    options.add_lint_arguments(argparser)
+    args = argparser.parse_args()
+
+    session = Session()
+    source = session[session.load(args.file)]
+
+    lint_options = options.lint_options(args)
+
+    if args.fix:
+        fixed, diagnostics = fix(source.contents, args, lint_options)
+        if fixed != source.contents:
+            with open(args.file, "w") as outf:
+                outf.write(fixed)
+            # Positions in any remaining diagnostic refer to the fixed text
+            source = session[session.add(args.file, fixed)]
+    else:
+        diagnostics = check(source.contents, args, lint_options)
+
+    for diagnostic in diagnostics:
+        report(diagnostic, source, args.message_format)
+    if any(d.severity == "error" for d in diagnostics):
+        raise SystemExit(1)
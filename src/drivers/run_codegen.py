@@ -1,18 +1,11 @@
-import sys
-import ply.lex as lex
-from core.lex_grammar import *
-from core.helper_types import *
-from core.quark_lexer import QuarkLexer
-from core.quark_parser import QuarkParser
+from drivers.common import argument_parser, parse_file
 import pytreetonative as cg
 
-lexer = QuarkLexer(lex.lex())
-
 if __name__ == "__main__":
-    with open(sys.argv[1], "r") as inputf:
-        lexer.input(inputf.read())
-        parser = QuarkParser(lexer.token_stream)
-        parser.parse()
+    argparser = argument_parser()
+    argparser.add_argument("file")
+    args = argparser.parse_args()
 
-        if parser.tree:
-            cg.initCodegen(parser.tree)
+    tree = parse_file(args, debug=True).tree
+    if tree:
+        cg.initCodegen(tree)
@@ -0,0 +1,40 @@
+import os
+import sys
+from utils import deadcode
+from drivers.common import argument_parser, project_root
+from drivers.run_index import update
+
+
+if __name__ == "__main__":
+    argparser = argument_parser()
+    argparser.add_argument("file", nargs="?", default=".", metavar="path")
+    argparser.add_argument(
+        "--entry",
+        action="append",
+        default=[],
+        metavar="MODULE",
+        help="module the program starts from (repeatable); default: modules nothing imports",
+    )
+    argparser.add_argument("--format", choices=["human", "json"], default="human")
+    args = argparser.parse_args()
+
+    root = project_root(args.file)
+    idx, _ = update(root, args)
+    report = deadcode.find(idx, args.entry)
+
+    for module in report["skipped_modules"]:
+        path = os.path.join(root, idx["modules"][module]["file"])
+        print(f"{path}: skipped module {module}, which does not parse; what it uses may be reported as unused", file=sys.stderr)
+
+    if args.format == "json":
+        print(deadcode.to_json(report))
+    else:
+        for module in report["unreachable_modules"]:
+            path = os.path.join(root, idx["modules"][module]["file"])
+            print(f"{path}: module {module} is not reachable from any entry module")
+        for fn in report["unused_functions"]:
+            path = os.path.join(root, idx["modules"][fn["module"]]["file"])
+            print(f"{path}:{fn['line']}:{fn['column']}: function {fn['name']} is never used")
+
+    # Non-zero when anything is found, for CI
+    raise SystemExit(1 if report["unreachable_modules"] or report["unused_functions"] else 0)
@@ -0,0 +1,33 @@
+from utils import deps
+from core.session import Session
+from core.diagnostics import QuarkError, report
+from drivers.common import argument_parser, load, project_root
+
+if __name__ == "__main__":
+    argparser = argument_parser()
+    argparser.add_argument("file")
+    argparser.add_argument("--format", choices=["dot", "json"], default="dot")
+    args = argparser.parse_args()
+
+    root = project_root(args.file)
+
+    session = Session()
+    sources = {}
+
+    def parse(path):
+        parsed = load(path, args, session)
+        if not parsed:
+            raise SystemExit(1)
+        sources[path] = parsed.source
+        return parsed.tree
+
+    graph = deps.build(root, args.file, parse)
+    cycle = deps.find_cycle(graph)
+    if cycle:
+        chain = " -> ".join([m for m, _ in cycle] + [cycle[0][0]])
+        module, tok = cycle[-1]
+        err = QuarkError("QK0020", f"Import cycle: {chain}.", tok)
+        report(err, sources[graph[module]["file"]], args.message_format)
+        raise SystemExit(1)
+
+    print(deps.to_dot(graph) if args.format == "dot" else deps.to_json(graph))
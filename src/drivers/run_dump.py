@@ -0,0 +1,42 @@
+from core import precedence
+from core.session import Session
+from drivers.common import argument_parser, tokenize, parse, reported
+
+PHASES = ["tokens", "ast"]
+
+
+def dump_tokens(source, args):
+    for i, tok in enumerate(tokenize(source.contents, args)):
+        print(i, tok)
+
+
+def dump_ast(source, args):
+    _, tree = parse(source.contents, args)
+    tree.print()
+
+
+def dump_precedence():
+    print("position\ttoken\tprecedence\tassoc\tnode")
+    for row in precedence.rows():
+        print("\t".join(row))
+
+
+if __name__ == "__main__":
+    argparser = argument_parser()
+    argparser.add_argument("file", nargs="?")
+    argparser.add_argument("--phase", choices=PHASES, default="ast")
+    argparser.add_argument(
+        "--precedence", action="store_true", help="print the operator table and exit"
+    )
+    args = argparser.parse_args()
+
+    if args.precedence:
+        dump_precedence()
+        raise SystemExit(0)
+    if not args.file:
+        argparser.error("a file is required unless --precedence is given")
+
+    session = Session()
+    source = session[session.load(args.file)]
+    with reported(source, args):
+        {"tokens": dump_tokens, "ast": dump_ast}[args.phase](source, args)
@@ -0,0 +1,33 @@
+import os
+from utils import dup, index
+from core.session import Session, LineIndex
+from drivers.common import argument_parser, load
+
+if __name__ == "__main__":
+    argparser = argument_parser()
+    argparser.add_argument("file", nargs="+", metavar="path", help="files or directories")
+    argparser.add_argument(
+        "--abstract", action="store_true", help="match copies with renamed names and changed literals"
+    )
+    argparser.add_argument("--min-nodes", type=int, default=12)
+    argparser.add_argument("--format", choices=["human", "json"], default="human")
+    args = argparser.parse_args()
+
+    paths = []
+    for path in args.file:
+        paths.extend(index.project_files(path) if os.path.isdir(path) else [path])
+
+    session = Session()
+    files, failed = [], False
+    for path in paths:
+        # Each file's edition comes from its own project config
+        parsed = load(path, args, session)
+        if not parsed:
+            failed = True
+            continue
+        files.append((path, parsed.tree, LineIndex(parsed.source.contents)))
+
+    groups = dup.find(files, abstract=args.abstract, min_nodes=args.min_nodes)
+    print(dup.to_json(groups) if args.format == "json" else dup.format_text(groups))
+    if failed:
+        raise SystemExit(1)
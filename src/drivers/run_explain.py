@@ -0,0 +1,11 @@
+import sys
+from core.error_codes import CATALOG
+
+if __name__ == "__main__":
+    code = sys.argv[1].upper()
+    if code not in CATALOG:
+        print(f"No such error code: {code}", file=sys.stderr)
+        raise SystemExit(1)
+
+    summary, explanation = CATALOG[code]
+    print(f"{code}: {summary}\n\n{explanation}")
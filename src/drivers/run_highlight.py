@@ -0,0 +1,37 @@
+import ply.lex as lex
+from utils import highlight, themes
+from core import config, options
+from core.session import Session
+from core.diagnostics import report
+from core.lex_grammar import *
+from drivers.common import argument_parser
+
+if __name__ == "__main__":
+    argparser = argument_parser()
+    argparser.add_argument("file", nargs="?")
+    argparser.add_argument("--format", choices=["ansi", "html"], default="ansi")
+    argparser.add_argument("--textmate", action="store_true")
+    argparser.add_argument("--theme", choices=sorted(themes.THEMES), help="overrides viz.theme")
+    args = argparser.parse_args()
+
+    # Keywords follow --edition, else the project's edition
+    edition = options.lexer_options(args, args.file or ".").edition
+
+    if args.textmate:
+        print(highlight.textmate_grammar(edition))
+    else:
+        session = Session()
+        source = session[session.load(args.file)]
+        errors = []
+        parts = list(highlight.spans(lex.lex(), source.contents, edition, errors))
+        if args.format == "html":
+            overrides = {"viz": {"theme": args.theme}} if args.theme else None
+            viz = config.load(args.file, overrides)["viz"]
+            theme = themes.resolve(viz["theme"], viz["styles"])
+            print(highlight.render_html(parts, theme), end="")
+        else:
+            print(highlight.render_ansi(parts), end="")
+        # Lexically invalid input is still printed; the rest of the file
+        # after the error is left uncolored
+        for err in errors:
+            report(err, source, "human")
@@ -0,0 +1,43 @@
+from utils import imports
+from core.session import Session
+from core.diagnostics import QuarkError, report
+from drivers.common import argument_parser, parse, parse_file, project_root
+
+if __name__ == "__main__":
+    argparser = argument_parser()
+    argparser.add_argument("file")
+    argparser.add_argument("--dry-run", action="store_true", help="report changes only")
+    args = argparser.parse_args()
+
+    # Modules with a file under the project root are grouped after the rest
+    root = project_root(args.file)
+
+    session = Session()
+    parsed = parse_file(args)
+    source, tree = parsed.source, parsed.tree
+
+    try:
+        organized = imports.organize(tree, source.contents, root)
+    except ValueError as err:
+        raise SystemExit(f"{source.path}: imports left unchanged: {err}.")
+    if not organized:
+        raise SystemExit(0)
+
+    lo, hi, text, removed = organized
+    for name in removed:
+        print(f"{source.path}: removed unused import '{name}'")
+    rewritten = source.contents[:lo] + text + source.contents[hi:]
+    try:
+        parse(rewritten, args)
+    except QuarkError as err:
+        report(err, session[session.add(args.file, rewritten)], args.message_format)
+        raise SystemExit("Organized source does not parse; left unchanged.")
+
+    if rewritten == source.contents:
+        print("Imports already organized.")
+    elif args.dry_run:
+        print(text)
+    else:
+        with open(args.file, "w") as outf:
+            outf.write(rewritten)
+        print("Imports organized.")
@@ -0,0 +1,38 @@
+import json
+from utils import index
+from core import options
+from core.session import Session
+from drivers.common import argument_parser, load, project_root
+
+
+def update(root, args):
+    """Refreshes the project index, reporting files that fail to parse."""
+    session = Session()
+
+    def parse(path):
+        parsed = load(path, args, session)
+        return parsed and (parsed.tree, parsed.source.contents)
+
+    # Symbols parsed under another edition or feature set may be wrong
+    settings = {
+        "edition": options.lexer_options(args, root).edition,
+        "features": sorted(args.feature),
+    }
+    return index.update(root, parse, settings)
+
+
+if __name__ == "__main__":
+    argparser = argument_parser()
+    argparser.add_argument("file", nargs="?", default=".", metavar="path")
+    argparser.add_argument("--format", choices=["summary", "json"], default="summary")
+    args = argparser.parse_args()
+
+    root = project_root(args.file)
+    idx, reparsed = update(root, args)
+
+    if args.format == "json":
+        print(json.dumps(idx, indent=2))
+    else:
+        modules = idx["modules"]
+        symbols = sum(len(m["definitions"]) for m in modules.values())
+        print(f"Indexed {len(modules)} modules, {symbols} symbols ({len(reparsed)} reparsed).")
@@ -1,15 +1,13 @@
-import sys
-import ply.lex as lex
-from core.lex_grammar import *
-from core.quark_lexer import QuarkLexer
-
-# Lexer
-lexer = QuarkLexer(lex.lex())
-
+from core.session import Session
+from drivers.common import argument_parser, tokenize, reported
 
 if __name__ == "__main__":
-    with open(sys.argv[1], "r") as inputf:
-        lexer.input(inputf.read())
-
-        for i, tok in enumerate(lexer.token_stream):
+    argparser = argument_parser()
+    argparser.add_argument("file")
+    args = argparser.parse_args()
+
+    session = Session()
+    source = session[session.load(args.file)]
+    with reported(source, args):
+        for i, tok in enumerate(tokenize(source.contents, args)):
             print(i, tok)
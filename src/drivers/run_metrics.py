@@ -0,0 +1,34 @@
+import json
+from core import config, metrics
+from core.session import LineIndex
+from drivers.common import argument_parser, parse_file
+
+COLUMNS = ["complexity", "nesting", "params", "lines"]
+
+if __name__ == "__main__":
+    argparser = argument_parser()
+    argparser.add_argument("file")
+    argparser.add_argument("--format", choices=["table", "json"], default="table")
+    args = argparser.parse_args()
+
+    parsed = parse_file(args)
+    results = metrics.collect(parsed.tree, LineIndex(parsed.source.contents))
+    thresholds = config.load(args.file)["metrics"]
+    over = {(r["name"], metric) for r, metric, _, _ in metrics.exceeded(results, thresholds)}
+
+    if args.format == "json":
+        rows = [
+            {
+                **{k: v for k, v in r.items() if k != "tok"},
+                "over": [m for m in COLUMNS if (r["name"], m) in over],
+            }
+            for r in results
+        ]
+        print(json.dumps({"thresholds": thresholds, "functions": rows}, indent=2))
+    else:
+        width = max([len("function")] + [len(r["name"]) for r in results])
+        print(f"{'function':<{width}}  line  " + "  ".join(f"{c:>10}" for c in COLUMNS))
+        for r in results:
+            # '!' marks a figure over its limit
+            cells = [f"{str(r[c]) + ('!' if (r['name'], c) in over else ''):>10}" for c in COLUMNS]
+            print(f"{r['name']:<{width}}  {r['line']:>4}  " + "  ".join(cells))
@@ -0,0 +1,42 @@
+from core import options, migrate
+from core.options import EDITIONS
+from core.session import Session
+from core.diagnostics import QuarkError, report, apply_suggestions
+from drivers.common import argument_parser, parse, reported
+
+if __name__ == "__main__":
+    argparser = argument_parser()
+    argparser.add_argument("file")
+    argparser.add_argument("--to", choices=EDITIONS, default=EDITIONS[-1], help="target edition")
+    argparser.add_argument("--dry-run", action="store_true", help="report changes only")
+    args = argparser.parse_args()
+
+    session = Session()
+    source = session[session.load(args.file)]
+    edition = options.lexer_options(args).edition
+
+    if EDITIONS.index(args.to) < EDITIONS.index(edition):
+        raise SystemExit(f"Cannot migrate from edition {edition} back to {args.to}.")
+
+    with reported(source, args):
+        tokens, tree = parse(source.contents, args, edition=edition)
+
+    changes = migrate.plan(tokens, tree, edition, args.to)
+    for tok, fix in changes:
+        line, col = source.line_col(tok.pos)
+        print(f"{source.path}:{line}:{col}: {fix.message}: '{source.contents[fix.pos:fix.end]}' -> '{fix.replacement}'")
+
+    migrated = apply_suggestions(source.contents, [fix for _, fix in changes])
+    try:
+        parse(migrated, args, edition=args.to)
+    except QuarkError as err:
+        # Report against the rewritten text, which is what failed
+        report(err, session[session.add(args.file, migrated)], args.message_format)
+        raise SystemExit(f"Migrated source does not parse under edition {args.to}; left unchanged.")
+
+    print(f"{len(changes)} change(s) from edition {edition} to {args.to}.")
+    if changes and not args.dry_run:
+        with open(args.file, "w") as outf:
+            outf.write(migrated)
+    if args.to != edition:
+        print(f"Set edition = \"{args.to}\" in quark.toml to build with the new edition.")
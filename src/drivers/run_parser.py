@@ -1,22 +1,41 @@
-import sys
-import ply.lex as lex
-from utils import treeviz
-from core.lex_grammar import *
-from core.quark_lexer import QuarkLexer
-from core.quark_parser import QuarkParser
-
-# Lexer
-lexer = QuarkLexer(lex.lex())
+from core import config
+from utils import treeviz, themes
+from drivers.common import argument_parser, parse_file
 
 if __name__ == "__main__":
-    with open(sys.argv[1], "r") as inputf:
-        lexer.input(inputf.read())
-        parser = QuarkParser(lexer.token_stream)
+    argparser = argument_parser()
+    argparser.add_argument("file")
+    argparser.add_argument("--output", help="DOT file to write (overrides viz.output)")
+    argparser.add_argument("--max-label", type=int, help="truncate labels to N characters")
+    argparser.add_argument(
+        "--merge-chains", action="store_true", default=None, help="draw single-child chains as one box"
+    )
+    argparser.add_argument("--max-nodes", type=int, help="stop drawing after N nodes")
+    argparser.add_argument("--theme", choices=sorted(themes.THEMES), help="overrides viz.theme")
+    args = argparser.parse_args()
+    viz_args = {
+        "output": args.output,
+        "max_label": args.max_label,
+        "merge_chains": args.merge_chains,
+        "max_nodes": args.max_nodes,
+        "theme": args.theme,
+    }
+    settings = config.load(
+        args.file, {"viz": {k: v for k, v in viz_args.items() if v is not None}}
+    )
+
+    tree = parse_file(args, debug=True).tree
 
-        parser.parse()
-        viz = treeviz.TreeViz()
-        if parser.tree:
-            viz.generate(parser.tree)
-            viz.save()
-        else:
-            print("Parser tree is Null.")
+    viz_settings = settings["viz"]
+    viz = treeviz.TreeViz(
+        clusters=viz_settings["clusters"],
+        max_label=viz_settings["max_label"] or None,
+        merge_chains=viz_settings["merge_chains"],
+        max_nodes=viz_settings["max_nodes"] or None,
+        theme=themes.resolve(viz_settings["theme"], viz_settings["styles"]),
+    )
+    if tree:
+        viz.generate(tree)
+        viz.save(settings["viz"]["output"])
+    else:
+        print("Parser tree is Null.")
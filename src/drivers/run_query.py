@@ -0,0 +1,13 @@
+from utils import treequery
+from drivers.common import argument_parser, parse_file
+
+if __name__ == "__main__":
+    argparser = argument_parser()
+    argparser.add_argument("file")
+    argparser.add_argument("query", help="selector, e.g. 'Function > Identifier[x]'")
+    args = argparser.parse_args()
+
+    parsed = parse_file(args)
+    for node in treequery.query(parsed.tree, args.query):
+        loc = "{}:{}".format(*parsed.source.line_col(node.tok.pos)) if node.tok else "-"
+        print(f"{args.file}:{loc}\t{node}")
@@ -0,0 +1,61 @@
+import os
+import json
+import argparse
+from utils import index, deps
+from core.session import LineIndex
+from drivers.common import argument_parser, project_root
+from drivers.run_index import update
+
+
+def position(text):
+    try:
+        line, column = (int(n) for n in text.split(":"))
+    except ValueError:
+        raise argparse.ArgumentTypeError(f"expected LINE:COLUMN, got '{text}'")
+    return line, column
+
+
+if __name__ == "__main__":
+    argparser = argument_parser()
+    argparser.add_argument("file")
+    argparser.add_argument("--at", type=position, required=True, metavar="LINE:COLUMN")
+    argparser.add_argument("--format", choices=["human", "json"], default="human")
+    args = argparser.parse_args()
+
+    root = project_root(args.file)
+    idx, _ = update(root, args)
+
+    with open(args.file) as f:
+        lines = LineIndex(f.read())
+    module = deps.module_of(root, args.file)
+    symbol = index.symbol_at(idx, module, lines.offset(*args.at))
+    if not symbol:
+        raise SystemExit(f"No top-level symbol at {args.file}:{args.at[0]}:{args.at[1]}.")
+
+    target, name = symbol
+    found = [
+        {"file": os.path.join(root, idx["modules"][source]["file"]), **ref}
+        for source, ref in index.references(idx, target, name)
+    ]
+    definition = index.definition(idx, target, name)
+
+    if args.format == "json":
+        print(
+            json.dumps(
+                {
+                    "module": target,
+                    "name": name,
+                    "definition": definition
+                    and {"file": os.path.join(root, idx["modules"][target]["file"]), **definition},
+                    "references": found,
+                },
+                indent=2,
+            )
+        )
+    else:
+        if definition:
+            path = os.path.join(root, idx["modules"][target]["file"])
+            print(f"{path}:{definition['line']}:{definition['column']}: definition of {target}.{name}")
+        for ref in found:
+            where = f" in {ref['function']}" if ref["function"] else ""
+            print(f"{ref['file']}:{ref['line']}:{ref['column']}: reference{where}")
@@ -0,0 +1,16 @@
+import json
+from utils import treestats
+from drivers.common import argument_parser, parse_file
+
+if __name__ == "__main__":
+    argparser = argument_parser()
+    argparser.add_argument("file")
+    argparser.add_argument("--json", action="store_true", help="print the stats as JSON")
+    args = argparser.parse_args()
+
+    parsed = parse_file(args)
+    stats = treestats.collect(parsed.tree, parsed.tokens)
+    if args.json:
+        print(json.dumps(stats, indent=2))
+    else:
+        print(treestats.format_text(stats))
@@ -224,6 +224,7 @@ class Lexer:
                 tok.value = m.group()
                 tok.lineno = self.lineno
                 tok.pos = pos
+                tok.end = m.end()
 
                 i = m.lastindex
                 func, tok.type = lexindexfunc[i]
@@ -262,6 +263,7 @@ class Lexer:
                     tok.lineno = self.lineno
                     tok.type = tok.value
                     tok.pos = pos
+                    tok.end = pos + 1
                     self.pos = pos + 1
                     return tok
 
@@ -273,6 +275,7 @@ class Lexer:
                     tok.type = 'error'
                     tok.lexer = self
                     tok.pos = pos
+                    tok.end = pos + 1
                     self.pos = pos
                     newtok = self.lexerrorf(tok)
                     if pos == self.pos:
@@ -293,7 +296,7 @@ class Lexer:
             tok.type = 'eof'
             tok.value = ''
             tok.lineno = self.lineno
-            tok.pos = pos
+            tok.pos = tok.end = pos
             tok.lexer = self
             self.pos = pos
             newtok = self.lexeoff(tok)
@@ -0,0 +1,59 @@
+import os
+import dataclasses
+import ply.lex as lex
+from core.lex_grammar import *
+from core.quark_lexer import QuarkLexer
+from core.quark_parser import QuarkParser
+from core import options
+from core.options import LexerOptions, ParserOptions
+from drivers.common import argument_parser
+
+lexer = QuarkLexer(lex.lex())
+
+ALL_FEATURES = ParserOptions(features=frozenset(["sets", "coalesce", "attributes"]))
+
+
+def parse(text, edition="2025", options=ALL_FEATURES):
+    lexer.input(text, options=LexerOptions(edition))
+    options = dataclasses.replace(options, edition=edition)
+    parser = QuarkParser(lexer.token_stream, debug=False, options=options)
+    parser.parse()
+    return parser.tree
+
+
+def expression(text, **kwargs):
+    """The tree of a single-expression program."""
+    return parse(text, **kwargs).children[0].children[0]
+
+
+def sexp(node):
+    """Compact shape of a tree: `(op left right)` for operators and ranges,
+    the token value for leaves, `_` for an Unbounded bound."""
+    if node.type.name == "Unbounded":
+        return "_"
+    if not node.children:
+        return str(node.tok.value)
+    return "(" + " ".join([str(node.tok.value)] + [sexp(c) for c in node.children]) + ")"
+
+
+def check_args(*argv):
+    """Arguments as run_check parses them; the file need not exist."""
+    parser = argument_parser()
+    parser.add_argument("file")
+    parser.add_argument("--fix", action="store_true")
+    options.add_lint_arguments(parser)
+    return parser.parse_args(list(argv) or ["/nonexistent/main.qrk"])
+
+
+def write_project(root, files):
+    """Writes {relative path: text} under `root`."""
+    for name, text in files.items():
+        path = os.path.join(root, name)
+        os.makedirs(os.path.dirname(path), exist_ok=True)
+        with open(path, "w") as f:
+            f.write(text)
+
+
+def parse_path(path):
+    with open(path) as f:
+        return parse(f.read())
@@ -0,0 +1,39 @@
+import unittest
+from core import build
+from core.diagnostics import QuarkError
+from core.validator import validate
+from tests.helpers import parse, sexp
+
+
+class AssignmentTest(unittest.TestCase):
+    def assertRejected(self, text, message):
+        with self.assertRaises(QuarkError) as ctx:
+            parse(text)
+        self.assertEqual(ctx.exception.code, "QK0008")
+        self.assertIn(message, str(ctx.exception))
+
+    def test_statement(self):
+        stmt = parse("p.x = a + 1\n").children[0].children[0]
+        self.assertEqual(sexp(stmt), "(= (. p x) (+ a 1))")
+
+    def test_nested_assignments_are_rejected(self):
+        for text in ["x = (a = 1)\n", "@f (a = 1)\n", "[a = 1]\n", "@f a = 1\n"]:
+            with self.subTest(text=text):
+                self.assertRejected(text, "cannot appear inside an expression")
+
+    def test_chained_assignment_is_rejected(self):
+        self.assertRejected("a = b = 0\n", "Chained assignment")
+
+    def test_validator_rejects_nested_assignments(self):
+        nested = build.assign("a", 1)
+        for node in [
+            build.assign("x", nested),
+            build.call("f", nested),
+            build.binary("+", "x", nested),
+        ]:
+            with self.subTest(node=node):
+                self.assertNotEqual(validate(build.compilation_unit(node)), [])
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,23 @@
+import unittest
+from core import build
+from core.validator import validate
+from tests.helpers import expression
+
+
+class BuildTest(unittest.TestCase):
+    def test_numbers_become_literals(self):
+        self.assertEqual(build.binary("+", "x", 1), expression("x + 1"))
+        self.assertEqual(build.range_(0, 2.5), expression("0..2.5"))
+        unit = build.compilation_unit(build.assign("x", 1), build.call("print", "x", 2))
+        self.assertEqual(validate(unit), [])
+
+    def test_validator_reports_stray_children(self):
+        node = build.binary("+", "x", "y")
+        node.children[1] = 1
+        errors = validate(build.compilation_unit(node))
+        self.assertEqual(len(errors), 1)
+        self.assertIn("not a tree node: 1", errors[0])
+
+
+if __name__ == "__main__":
+    unittest.main()
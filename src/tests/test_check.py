@@ -0,0 +1,52 @@
+import unittest
+from core import options
+from drivers import run_check
+from tests.helpers import check_args
+
+
+def fix(text, *argv):
+    args = check_args(*argv)
+    return run_check.fix(text, args, options.lint_options(args))
+
+
+class FixTest(unittest.TestCase):
+    def test_unclosed_bracket_in_block(self):
+        fixed, diagnostics = fix("if x:\n    y = [1, 2\n")
+        self.assertEqual(fixed, "if x:\n    y = [1, 2]\n")
+        self.assertEqual(diagnostics, [])
+
+    def test_unclosed_bracket_at_top_level(self):
+        fixed, _ = fix("y = (1 + 2\nz = 3\n")
+        self.assertEqual(fixed, "y = (1 + 2)\nz = 3\n")
+
+    def test_else_if_uses_the_edition_keyword(self):
+        text = "if x:\n    a = 1\nelse if y:\n    a = 2\n"
+        for edition, keyword in [("2024", "elif"), ("2025", "elseif")]:
+            with self.subTest(edition=edition):
+                fixed, diagnostics = fix(text, "--edition", edition, "/nonexistent/main.qrk")
+                self.assertEqual(fixed, text.replace("else if", keyword))
+                self.assertEqual(diagnostics, [])
+
+
+class DeprecationTest(unittest.TestCase):
+    text = "if x:\n    a = 1\nelif y:\n    a = 2\n"
+
+    def test_elif_is_deprecated_from_2025(self):
+        args = check_args()
+        [diagnostic] = run_check.check(self.text, args, options.lint_options(args))
+        self.assertEqual((diagnostic.code, diagnostic.severity), ("QK0018", "warning"))
+        fixed, diagnostics = fix(self.text)
+        self.assertEqual(fixed, self.text.replace("elif", "elseif"))
+        self.assertEqual(diagnostics, [])
+
+    def test_elif_is_current_in_2024(self):
+        fixed, diagnostics = fix(self.text, "--edition", "2024", "/nonexistent/main.qrk")
+        self.assertEqual((fixed, diagnostics), (self.text, []))
+
+    def test_allowed(self):
+        fixed, diagnostics = fix(self.text, "-A", "deprecated", "/nonexistent/main.qrk")
+        self.assertEqual((fixed, diagnostics), (self.text, []))
+
+
+if __name__ == "__main__":
+    unittest.main()
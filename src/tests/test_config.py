@@ -0,0 +1,47 @@
+import os
+import tempfile
+import unittest
+from core import config, options
+from tests.helpers import check_args
+
+
+class ConfigTest(unittest.TestCase):
+    def setUp(self):
+        self.dir = tempfile.TemporaryDirectory()
+        self.file = os.path.join(self.dir.name, "main.qrk")
+
+    def tearDown(self):
+        self.dir.cleanup()
+
+    def write(self, text):
+        with open(os.path.join(self.dir.name, config.PROJECT_FILE), "w") as f:
+            f.write(text)
+
+    def test_lint_levels(self):
+        self.write('[lints]\njuxtaposition = "allow"\nshadowing = "deny"\n')
+        lint_options = options.lint_options(check_args(self.file))
+        self.assertEqual(lint_options.level("juxtaposition"), "allow")
+        self.assertEqual(lint_options.level("shadowing"), "deny")
+        self.assertEqual(lint_options.level("unused-variable"), "warn")
+
+    def test_command_line_wins(self):
+        self.write('[lints]\nshadowing = "deny"\n')
+        lint_options = options.lint_options(check_args("-A", "shadowing", self.file))
+        self.assertEqual(lint_options.level("shadowing"), "allow")
+
+    def test_unknown_lint(self):
+        self.write('[lints]\nbogus = "allow"\n')
+        with self.assertRaises(SystemExit):
+            options.lint_options(check_args(self.file))
+
+    def test_edition_and_metrics(self):
+        self.write('edition = "2024"\n[metrics]\nmax_params = 2\n')
+        settings = config.load(self.file)
+        self.assertEqual(settings["edition"], "2024")
+        self.assertEqual(settings["metrics"]["max_params"], 2)
+        default = config.DEFAULTS["metrics"]["max_nesting"]
+        self.assertEqual(settings["metrics"]["max_nesting"], default)
+
+
+if __name__ == "__main__":
+    unittest.main()
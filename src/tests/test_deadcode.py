@@ -0,0 +1,57 @@
+import tempfile
+import unittest
+from utils import deadcode, index
+from core.diagnostics import QuarkError
+from tests.helpers import parse_path, write_project
+
+
+class DeadcodeTest(unittest.TestCase):
+    def setUp(self):
+        self.dir = tempfile.TemporaryDirectory()
+
+    def tearDown(self):
+        self.dir.cleanup()
+
+    def find(self, files, entries=None):
+        write_project(self.dir.name, files)
+
+        def parse_file(path):
+            try:
+                tree = parse_path(path)
+            except QuarkError:
+                return None
+            with open(path) as f:
+                return tree, f.read()
+
+        idx, _ = index.update(self.dir.name, parse_file)
+        report = deadcode.find(idx, entries)
+        report["unused_functions"] = [
+            f"{f['module']}.{f['name']}" for f in report["unused_functions"]
+        ]
+        return report
+
+    def test_unused_functions(self):
+        report = self.find(
+            {
+                "main.qrk": "use lib: used\n@used 1\n",
+                # `only_dead` is only called by a dead function
+                "lib.qrk": "fn used x:\n    x\nfn dead:\n    @only_dead\nfn only_dead:\n    1\n",
+            }
+        )
+        self.assertEqual(report["unused_functions"], ["lib.dead", "lib.only_dead"])
+        self.assertEqual(report["unreachable_modules"], [])
+
+    def test_unreachable_modules(self):
+        report = self.find({"main.qrk": "x = 1\n", "old.qrk": "y = 2\n"}, entries=["main"])
+        self.assertEqual(report["unreachable_modules"], ["old"])
+
+    def test_modules_that_do_not_parse_are_skipped(self):
+        report = self.find(
+            {"main.qrk": "use lib: f\n@f 1\nx = (\n", "lib.qrk": "fn f x:\n    x\n"}
+        )
+        self.assertEqual(report["skipped_modules"], ["main"])
+        self.assertEqual(report["unreachable_modules"], [])
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,36 @@
+import os
+import tempfile
+import unittest
+from utils import deps
+from tests.helpers import parse_path, write_project
+
+
+class DepsTest(unittest.TestCase):
+    def setUp(self):
+        self.dir = tempfile.TemporaryDirectory()
+        self.root = self.dir.name
+
+    def tearDown(self):
+        self.dir.cleanup()
+
+    def graph(self, files):
+        write_project(self.root, files)
+        return deps.build(self.root, os.path.join(self.root, "main.qrk"), parse_path)
+
+    def test_graph(self):
+        graph = self.graph({"main.qrk": "use lib.util\nuse io\n", "lib/util.qrk": "x = 1\n"})
+        self.assertEqual(set(graph), {"main", "lib.util", "io"})
+        self.assertEqual([m for m, _ in graph["main"]["imports"]], ["lib.util", "io"])
+        # No file under the root: the standard library
+        self.assertIsNone(graph["io"]["file"])
+        self.assertIsNone(deps.find_cycle(graph))
+
+    def test_cycle(self):
+        graph = self.graph({"main.qrk": "use a\n", "a.qrk": "use b\n", "b.qrk": "use a\n"})
+        cycle = deps.find_cycle(graph)
+        self.assertEqual([m for m, _ in cycle], ["a", "b"])
+        self.assertTrue(all(tok.type == "USE" for _, tok in cycle))
+
+
+if __name__ == "__main__":
+    unittest.main()
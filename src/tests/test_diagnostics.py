@@ -0,0 +1,22 @@
+import unittest
+from core.diagnostics import QuarkError
+from tests.helpers import parse
+
+
+def error(text, **kwargs):
+    with unittest.TestCase().assertRaises(QuarkError) as ctx:
+        parse(text, **kwargs)
+    return ctx.exception
+
+
+class ExpectedTokensTest(unittest.TestCase):
+    def test_keywords_follow_the_edition(self):
+        latest = str(error("x = )\n"))
+        self.assertIn("'not'", latest)
+        older = str(error("x = )\n", edition="2024"))
+        self.assertNotIn("'not'", older)
+        self.assertIn("'~'", older)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,41 @@
+import unittest
+from core import effects
+from tests.helpers import parse
+from tests.test_lints import codes
+
+
+def reasons(text):
+    return {name: fx.reasons for name, fx in effects.analyze(parse(text)).functions.items()}
+
+
+class EffectsTest(unittest.TestCase):
+    def test_pure(self):
+        self.assertEqual(reasons("fn sq x: x * x\n"), {"sq": []})
+
+    def test_effects_spread_to_callers(self):
+        found = reasons("fn p x:\n    @print x\nfn q x:\n    @p x\n")
+        self.assertIn("not defined in this file", found["p"][0])
+        self.assertEqual(found["q"], ["calls 'p', which is effectful"])
+
+    def test_module_state(self):
+        found = reasons("n = 1\nfn r:\n    n\n")
+        self.assertEqual(found["r"], ["reads the module-level variable 'n'"])
+
+    def test_recursion_alone_is_pure(self):
+        found = reasons("fn a x:\n    @b x\nfn b x:\n    @a x\n")
+        self.assertEqual(found, {"a": [], "b": []})
+
+
+class DiscardedResultTest(unittest.TestCase):
+    def test_pure_call_statement(self):
+        self.assertEqual(codes("fn sq x: x * x\n@sq 4\n"), ["QK0023"])
+
+    def test_last_statement_is_the_result(self):
+        self.assertEqual(codes("fn sq x: x * x\nfn g x:\n    @sq x\n"), [])
+
+    def test_effectful_call(self):
+        self.assertEqual(codes("fn p x:\n    @print x\n@p 1\n"), [])
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,100 @@
+import random
+import re
+import string
+import unittest
+from core import build
+from core.lex_grammar import KEYWORDS
+from core.diagnostics import QuarkError
+from core.options import LexerOptions
+from tests.helpers import lexer, parse
+
+# Fixed so a failure reproduces; raise ITERATIONS locally for a longer run
+SEED = 1641
+ITERATIONS = 2000
+
+WORDS = (
+    sorted(KEYWORDS["2025"])
+    + sorted(build.OPERATORS)
+    + ["(", ")", "[", "]", "{", "}", ",", ":", "@", "~", "\n", "\n    "]
+    + ["x", "1", "2.5", '"s"', "// c"]
+)
+
+
+# Valid programs for _mutations to break
+CORPUS = [
+    "fn add a, b:\n    if a > b:\n        a - b\n"
+    "    elseif a == b:\n        0\n    else:\n        a + b\n",
+    "use math: sqrt as root\nx = 2.5\nr = 1..10\n@print root x\n",
+    "struct Point: x, y\n@Point 1, 2\np.x = a ?? b\n",
+    "enum Color: Red, Green\nc = [1, 2, 3]\nd = {1, 2}\n@[inline]\nfn f x:\n    ~x\n",
+]
+
+
+def _characters(rng):
+    return "".join(rng.choice(string.printable) for _ in range(rng.randint(0, 40)))
+
+
+def _words(rng):
+    return " ".join(rng.choice(WORDS) for _ in range(rng.randint(0, 20)))
+
+
+def _mutations(rng):
+    """A corpus program with a few words deleted, duplicated or swapped, so
+    most of the input still gets past the lexer."""
+    words = re.split(r"( +)", rng.choice(CORPUS))
+    for _ in range(rng.randint(1, 3)):
+        i, j = rng.randrange(len(words)), rng.randrange(len(words))
+        match rng.randrange(3):
+            case 0:
+                del words[i]
+            case 1:
+                words.insert(i, words[j])
+            case 2:
+                words[i], words[j] = words[j], words[i]
+        if not words:
+            break
+    return "".join(words)
+
+
+class FuzzTest(unittest.TestCase):
+    """The lexer and parser report bad input as QuarkError and never raise
+    anything else."""
+
+    def check(self, generate, stage):
+        rng = random.Random(SEED)
+        for _ in range(ITERATIONS):
+            text = generate(rng)
+            for edition in KEYWORDS:
+                try:
+                    stage(text, edition)
+                except QuarkError:
+                    pass
+                except Exception as e:
+                    self.fail(f"{type(e).__name__}: {e} on {text!r} (edition {edition})")
+
+    @staticmethod
+    def lex(text, edition):
+        lexer.input(text, options=LexerOptions(edition))
+        list(lexer.token_stream)
+
+    def test_lexer_characters(self):
+        self.check(_characters, self.lex)
+
+    def test_lexer_words(self):
+        self.check(_words, self.lex)
+
+    def test_lexer_mutations(self):
+        self.check(_mutations, self.lex)
+
+    def test_parser_characters(self):
+        self.check(_characters, lambda text, edition: parse(text, edition=edition))
+
+    def test_parser_words(self):
+        self.check(_words, lambda text, edition: parse(text, edition=edition))
+
+    def test_parser_mutations(self):
+        self.check(_mutations, lambda text, edition: parse(text, edition=edition))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,58 @@
+import json
+import unittest
+from utils import highlight
+from tests.helpers import lexer
+
+SOURCE = 'fn f x:\n    ret = "s" // note\n    x + 1.5\n'
+
+
+def spans(source, **kwargs):
+    return list(highlight.spans(lexer.lexer, source, **kwargs))
+
+
+class SpansTest(unittest.TestCase):
+    def test_cover_the_source(self):
+        self.assertEqual("".join(text for _, text in spans(SOURCE)), SOURCE)
+
+    def test_categories(self):
+        found = {text: category for category, text in spans(SOURCE) if category}
+        self.assertEqual(found["fn"], "keyword")
+        self.assertEqual(found["f"], "identifier")
+        self.assertEqual(found['"s"'], "string")
+        self.assertEqual(found["// note"], "comment")
+        self.assertEqual(found["1.5"], "number")
+
+    def test_rejected_text_stays_uncolored(self):
+        errors = []
+        parts = spans("x = $ 1\n", errors=errors)
+        self.assertEqual("".join(text for _, text in parts), "x = $ 1\n")
+        self.assertEqual(parts[-1], (None, " $ 1\n"))
+        self.assertEqual([e.code for e in errors], ["QK0025"])
+
+    def test_render(self):
+        parts = spans("fn f: 1\n")
+        self.assertIn("fn", highlight.render_ansi(parts))
+        html = highlight.render_html(parts)
+        self.assertIn("fn", html)
+        self.assertNotIn("<script", highlight.render_html(spans('"<script>"\n')))
+
+
+class EditionTest(unittest.TestCase):
+    def test_keywords_follow_the_edition(self):
+        for edition, category in [("2024", "identifier"), ("2025", "keyword")]:
+            with self.subTest(edition=edition):
+                parts = spans("is = not\n", edition=edition)
+                self.assertEqual(parts[0], (category, "is"))
+
+    def test_textmate_grammar(self):
+        def keywords(edition):
+            grammar = json.loads(highlight.textmate_grammar(edition))
+            [pattern] = [p for p in grammar["patterns"] if p["name"] == "keyword.control.quark"]
+            return pattern["match"]
+
+        self.assertIn("elseif", keywords("2025"))
+        self.assertNotIn("elseif", keywords("2024"))
+
+
+if __name__ == "__main__":
+    unittest.main()
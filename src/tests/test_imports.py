@@ -0,0 +1,29 @@
+import unittest
+from utils import imports
+from tests.helpers import parse
+
+
+def organize(text):
+    return imports.organize(parse(text), text, "/nonexistent")
+
+
+class OrganizeTest(unittest.TestCase):
+    def test_aliases_and_whole_modules_sort_together(self):
+        text = "use math: sqrt, sqrt as s\nuse math as m\nuse math\n@sqrt m\n@s math\n"
+        _, _, organized, removed = organize(text)
+        self.assertEqual(organized, "use math\nuse math: sqrt, sqrt as s\nuse math as m")
+        self.assertEqual(removed, [])
+
+    def test_removes_unused(self):
+        _, _, organized, removed = organize("use io\nuse math: sqrt, floor\n@sqrt 2\n")
+        self.assertEqual(organized, "use math: sqrt")
+        self.assertEqual(removed, ["io", "floor"])
+
+    def test_refuses_comments(self):
+        for text in ["use math // note\n@print math\n", "use io\n// note\nuse math\n"]:
+            with self.subTest(text=text), self.assertRaises(ValueError):
+                organize(text)
+
+
+if __name__ == "__main__":
+    unittest.main()
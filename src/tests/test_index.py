@@ -0,0 +1,83 @@
+import os
+import tempfile
+import unittest
+from utils import index
+from core.session import LineIndex
+from core.diagnostics import QuarkError
+from tests.helpers import parse, parse_path, write_project
+
+
+class IndexTest(unittest.TestCase):
+    def setUp(self):
+        self.dir = tempfile.TemporaryDirectory()
+        self.root = self.dir.name
+        with open(os.path.join(self.root, "main.qrk"), "w") as f:
+            f.write("is = 1\n")
+
+    def tearDown(self):
+        self.dir.cleanup()
+
+    def update(self, edition):
+        def parse_file(path):
+            with open(path) as f:
+                text = f.read()
+            try:
+                return parse(text, edition=edition), text
+            except QuarkError:
+                return None
+
+        return index.update(self.root, parse_file, {"edition": edition, "features": []})
+
+    def test_unchanged_files_are_reused(self):
+        self.update("2024")
+        _, reparsed = self.update("2024")
+        self.assertEqual(reparsed, [])
+
+    def test_changed_settings_reparse_everything(self):
+        idx, _ = self.update("2024")
+        self.assertIn("is", idx["modules"]["main"]["definitions"])
+        idx, reparsed = self.update("2025")
+        self.assertEqual(reparsed, ["main"])
+        self.assertTrue(idx["modules"]["main"]["error"])
+
+
+class ReferencesTest(unittest.TestCase):
+    def setUp(self):
+        self.dir = tempfile.TemporaryDirectory()
+        self.main = "use lib: helper as h\nuse lib\nfn run:\n    @h lib.n\n@run\n"
+        write_project(
+            self.dir.name, {"lib.qrk": "fn helper x:\n    x\nn = 1\n", "main.qrk": self.main}
+        )
+
+        def parse_file(path):
+            with open(path) as f:
+                return parse_path(path), f.read()
+
+        self.index, _ = index.update(self.dir.name, parse_file)
+
+    def tearDown(self):
+        self.dir.cleanup()
+
+    def refs(self, module, name):
+        return [
+            (source, ref["line"], ref["function"], ref["import"])
+            for source, ref in index.references(self.index, module, name)
+        ]
+
+    def test_through_an_alias(self):
+        self.assertEqual(
+            self.refs("lib", "helper"), [("main", 1, None, True), ("main", 4, "run", False)]
+        )
+
+    def test_through_a_module(self):
+        self.assertEqual(self.refs("lib", "n"), [("main", 4, "run", False)])
+
+    def test_symbol_at(self):
+        # `@h` on line 4 names lib.helper
+        pos = LineIndex(self.main).offset(4, 6)
+        self.assertEqual(index.symbol_at(self.index, "main", pos), ("lib", "helper"))
+        self.assertEqual(index.definition(self.index, "lib", "helper")["line"], 1)
+
+
+if __name__ == "__main__":
+    unittest.main()
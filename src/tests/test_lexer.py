@@ -0,0 +1,53 @@
+import unittest
+from core.diagnostics import QuarkError
+from core.options import LexerOptions
+from tests.helpers import lexer
+
+
+def error(text):
+    lexer.input(text, options=LexerOptions())
+    with unittest.TestCase().assertRaises(QuarkError) as ctx:
+        list(lexer.token_stream)
+    return ctx.exception
+
+
+def apply(text, suggestion):
+    return text[: suggestion.pos] + suggestion.replacement + text[suggestion.end :]
+
+
+class IndentationErrorTest(unittest.TestCase):
+    def test_missing_block(self):
+        err = error("if x:\nx = 1\n")
+        self.assertEqual(err.code, "QK0002")
+        [fix] = err.suggestions
+        self.assertFalse(fix.safe)
+        self.assertEqual(apply("if x:\nx = 1\n", fix), "if x: pass\nx = 1\n")
+
+    def test_missing_colon(self):
+        err = error("if x\n    y = 2\n")
+        self.assertEqual(err.code, "QK0003")
+        [fix] = err.suggestions
+        # Safe only after a line that opens a block
+        self.assertTrue(fix.safe)
+        self.assertEqual(apply("if x\n    y = 2\n", fix), "if x:\n    y = 2\n")
+
+    def test_unexpected_indent(self):
+        err = error("x = 1\n    y = 2\n")
+        self.assertEqual(err.code, "QK0003")
+        self.assertFalse(err.suggestions[0].safe)
+
+    def test_inconsistent_dedent(self):
+        err = error("if x:\n        a\n    b\n")
+        self.assertEqual(err.code, "QK0004")
+        self.assertEqual(err.suggestions, [])
+
+
+class IllegalCharacterTest(unittest.TestCase):
+    def test_reported_with_span(self):
+        err = error("x = $\n")
+        self.assertEqual(err.code, "QK0025")
+        self.assertEqual((err.tok.pos, err.tok.end), (4, 5))
+
+
+if __name__ == "__main__":
+    unittest.main()
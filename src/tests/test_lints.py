@@ -0,0 +1,95 @@
+import unittest
+from core import lints
+from core.options import LintOptions
+from tests.helpers import parse
+
+
+def diagnostics(text, **kwargs):
+    return lints.run(parse(text), text, LintOptions(**kwargs))
+
+
+def codes(text, **kwargs):
+    return [d.code for d in diagnostics(text, **kwargs)]
+
+
+class LocalLintTest(unittest.TestCase):
+    def test_unused_variable(self):
+        self.assertEqual(codes("fn f a:\n    x = 1\n    a\n"), ["QK0015"])
+        # Parameters and `_` names are exempt
+        self.assertEqual(codes("fn f a:\n    _x = 1\n    1\n"), [])
+
+    def test_shadowing(self):
+        [diagnostic] = diagnostics("x = 1\nfn f:\n    x = 2\n    x\n")
+        self.assertEqual(diagnostic.code, "QK0016")
+        self.assertIn("line 1", str(diagnostic))
+
+    def test_juxtaposition(self):
+        [diagnostic] = diagnostics("print x\n")
+        self.assertEqual(diagnostic.code, "QK0017")
+        [fix] = diagnostic.suggestions
+        self.assertEqual((fix.pos, fix.replacement, fix.safe), (0, "@", False))
+        self.assertEqual(codes("print x; y\n"), ["QK0017"])
+
+
+class LevelTest(unittest.TestCase):
+    text = "fn f a:\n    x = 1\n    a\n"
+
+    def test_allow(self):
+        self.assertEqual(codes(self.text, levels=(("unused-variable", "allow"),)), [])
+
+    def test_deny(self):
+        [diagnostic] = diagnostics(self.text, levels=(("unused-variable", "deny"),))
+        self.assertEqual(diagnostic.severity, "error")
+
+    def test_later_levels_win(self):
+        levels = (("unused-variable", "deny"), ("unused-variable", "allow"))
+        self.assertEqual(codes(self.text, levels=levels), [])
+
+    def test_deny_warnings(self):
+        [diagnostic] = diagnostics(self.text, deny_warnings=True)
+        self.assertEqual(diagnostic.severity, "error")
+        allowed = (("unused-variable", "allow"),)
+        self.assertEqual(codes(self.text, levels=allowed, deny_warnings=True), [])
+
+
+class UnusedAssignmentTest(unittest.TestCase):
+    def test_removes_a_pure_dead_store_whole(self):
+        text = "fn first xs:\n    item = 0\n    item = xs.head\n    item\n"
+        [diagnostic] = diagnostics(text)
+        self.assertEqual(diagnostic.code, "QK0022")
+        [fix] = diagnostic.suggestions
+        self.assertTrue(fix.safe)
+        self.assertEqual(text[: fix.pos] + text[fix.end :], text.replace("    item = 0\n", ""))
+
+    def test_keeps_the_value_when_the_store_is_last(self):
+        text = "fn f xs:\n    item = xs\n    if item:\n        item = 1\n"
+        fixes = [s for d in diagnostics(text) if d.code == "QK0022" for s in d.suggestions]
+        self.assertTrue(fixes)
+        self.assertFalse(any(fix.safe for fix in fixes))
+
+
+class UninitializedTest(unittest.TestCase):
+    def test_read_before_assignment(self):
+        [diagnostic] = diagnostics("fn f:\n    y\n    y = 1\n    y\n")
+        self.assertEqual(diagnostic.code, "QK0021")
+        self.assertIn("is read before", str(diagnostic))
+        self.assertFalse(diagnostic.suggestions[0].safe)
+
+    def test_assigned_on_some_paths(self):
+        [diagnostic] = diagnostics("fn f c:\n    if c:\n        y = 1\n    y\n")
+        self.assertIn("may be read before", str(diagnostic))
+
+    def test_assigned_on_every_path(self):
+        text = "fn f c:\n    if c:\n        y = 1\n    else:\n        y = 2\n    y\n"
+        self.assertEqual(codes(text), [])
+
+    def test_overwritten_on_every_path(self):
+        text = "fn f c:\n    y = 0\n    if c:\n        y = 1\n    else:\n        y = 2\n    y\n"
+        self.assertEqual(codes(text), ["QK0022"])
+        # Read on the path that skips the branch
+        text = "fn f c:\n    y = 0\n    if c:\n        y = 1\n    y\n"
+        self.assertEqual(codes(text), [])
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,44 @@
+import unittest
+from core import metrics
+from core.session import LineIndex
+from tests.helpers import parse
+from tests.test_lints import diagnostics
+
+TEXT = """fn outer a, b:
+    if a:
+        if b:
+            1
+        elseif a == b:
+            2
+    fn inner:
+        3
+    @inner
+"""
+
+
+class MetricsTest(unittest.TestCase):
+    def test_collect(self):
+        outer, inner = metrics.collect(parse(TEXT), LineIndex(TEXT))
+        self.assertEqual(
+            {k: outer[k] for k in ["name", "line", "complexity", "nesting", "params", "lines"]},
+            {"name": "outer", "line": 1, "complexity": 4, "nesting": 2, "params": 2, "lines": 9},
+        )
+        # Nested functions are measured on their own, under a qualified name
+        self.assertEqual(
+            (inner["name"], inner["complexity"], inner["lines"]), ("outer.inner", 1, 2)
+        )
+
+    def test_exceeded(self):
+        results = metrics.collect(parse(TEXT), LineIndex(TEXT))
+        limits = {"max_nesting": 1, "max_params": 0}
+        found = [(r["name"], *rest) for r, *rest in metrics.exceeded(results, limits)]
+        self.assertEqual(found, [("outer", "nesting", 2, 1)])
+
+    def test_complexity_lint(self):
+        [diagnostic] = diagnostics(TEXT, thresholds={"max_complexity": 3})
+        self.assertEqual(diagnostic.code, "QK0024")
+        self.assertEqual(str(diagnostic), "'outer' has a complexity of 4; the limit is 3.")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,73 @@
+import os
+import subprocess
+import sys
+import tempfile
+import unittest
+from core import migrate
+from core.options import LexerOptions
+from tests.helpers import lexer, parse
+
+
+def renames(text):
+    lexer.input(text, options=LexerOptions("2024"))
+    tokens = list(lexer.token_stream)
+    changes = migrate.plan(tokens, parse(text, edition="2024"), "2024", "2025")
+    return [(tok.value, fix.replacement) for tok, fix in changes]
+
+
+class MigrateTest(unittest.TestCase):
+    def test_renames_new_keywords(self):
+        self.assertEqual(renames("is = 1\n"), [("is", "is_")])
+
+    def test_rename_avoids_existing_names(self):
+        self.assertEqual(
+            renames("is = 1\nis_ = 2\nx = is + is_\n"), [("is", "is__"), ("is", "is__")]
+        )
+
+    def test_replaces_deprecated_syntax(self):
+        self.assertEqual(renames("if a:\n    b = 1\nelif c:\n    b = 2\n"), [("elif", "elseif")])
+
+
+class RunMigrateTest(unittest.TestCase):
+    """The driver, end to end."""
+
+    def setUp(self):
+        self.dir = tempfile.TemporaryDirectory()
+        self.file = os.path.join(self.dir.name, "main.qrk")
+
+    def tearDown(self):
+        self.dir.cleanup()
+
+    def migrate(self, text, *argv):
+        with open(self.file, "w") as f:
+            f.write(text)
+        src = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+        result = subprocess.run(
+            [sys.executable, "-m", "drivers.run_migrate", *argv, self.file],
+            cwd=src,
+            capture_output=True,
+            text=True,
+        )
+        with open(self.file) as f:
+            return result, f.read()
+
+    def test_rewrites_the_file(self):
+        text = "is = 1\nif is:\n    x = 1\nelif x:\n    x = 2\n"
+        result, text = self.migrate(text, "--edition", "2024")
+        self.assertEqual(result.returncode, 0, result.stderr)
+        self.assertEqual(text, "is_ = 1\nif is_:\n    x = 1\nelseif x:\n    x = 2\n")
+        self.assertIn("3 change(s) from edition 2024 to 2025.", result.stdout)
+
+    def test_dry_run(self):
+        result, text = self.migrate("is = 1\n", "--edition", "2024", "--dry-run")
+        self.assertEqual(text, "is = 1\n")
+        self.assertIn("'is' -> 'is_'", result.stdout)
+
+    def test_refuses_to_go_back(self):
+        result, _ = self.migrate("x = 1\n", "--to", "2024")
+        self.assertNotEqual(result.returncode, 0)
+        self.assertIn("Cannot migrate from edition 2025 back to 2024.", result.stderr)
+
+
+if __name__ == "__main__":
+    unittest.main()
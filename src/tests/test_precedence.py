@@ -0,0 +1,87 @@
+import unittest
+from core import build
+from core.precedence import INFIX, Assoc, Binding
+from core.expr_parser import ExprParser
+from core.helper_types import NodeType, Precedence, TreeNode
+from core.diagnostics import QuarkError
+from tests.helpers import expression, sexp
+
+# Source text of each infix token type
+TEXT = {type: text for text, type in build.OPERATORS.items() if type in INFIX}
+
+# Chained non-associative operators and the error each gives
+NON_ASSOC_CODES = {"EQUALS": "QK0008"}
+
+# Operators that only combine with some operands (`.` needs a name on the
+# right, `=` a target on the left), left out of the mixed-operator matrix
+SPECIAL = {"DOT", "EQUALS"}
+
+
+class AssociativityTest(unittest.TestCase):
+    """`a op b op c` for every operator in the precedence table."""
+
+    def test_table_has_text_for_every_operator(self):
+        self.assertEqual(set(TEXT), set(INFIX))
+
+    def test_chains(self):
+        for type, binding in INFIX.items():
+            op = TEXT[type]
+            text = f"a {op} b {op} c" if type != "DOT" else "a.b.c"
+            with self.subTest(op=op, assoc=binding.assoc):
+                if binding.assoc == Assoc.NonAssoc:
+                    with self.assertRaises(QuarkError) as ctx:
+                        expression(text)
+                    self.assertEqual(ctx.exception.code, NON_ASSOC_CODES.get(type, "QK0019"))
+                elif binding.assoc == Assoc.Left:
+                    self.assertEqual(sexp(expression(text)), f"({op} ({op} a b) c)")
+                else:
+                    self.assertEqual(sexp(expression(text)), f"({op} a ({op} b c))")
+
+    def test_mixed(self):
+        ops = [t for t, b in INFIX.items() if t not in SPECIAL and b.assoc != Assoc.NonAssoc]
+        for first in ops:
+            for second in ops:
+                p1, p2 = INFIX[first].precedence, INFIX[second].precedence
+                if p1 == p2 and INFIX[first].assoc != INFIX[second].assoc:
+                    continue
+                a, b = TEXT[first], TEXT[second]
+                left = p1 > p2 or (p1 == p2 and INFIX[first].assoc == Assoc.Left)
+                expected = f"({b} ({a} x y) z)" if left else f"({a} x ({b} y z))"
+                with self.subTest(first=a, second=b):
+                    self.assertEqual(sexp(expression(f"x {a} y {b} z")), expected)
+
+    def test_parentheses_override(self):
+        self.assertEqual(sexp(expression("a - (b - c)")), "(- a (- b c))")
+        self.assertEqual(sexp(expression("(a..b)")), "(.. a b)")
+
+
+class ExtensionTest(unittest.TestCase):
+    """Operators can be added through ExprParser.extensions without touching
+    the shared tables."""
+
+    def test_register_infix(self):
+        def pipe(expr_parser):
+            def parselet(left):
+                node = TreeNode(NodeType.Operator, expr_parser.parser.prev, [left])
+                binding = expr_parser.infix_bindings["PIPE"]
+                node.children.append(expr_parser.parse(precedence=binding.right_precedence()))
+                return node
+
+            expr_parser.register_infix(
+                "PIPE", parselet, Binding(Precedence.Term, Assoc.Left, NodeType.Operator)
+            )
+
+        ExprParser.extensions.append(pipe)
+        try:
+            self.assertEqual(sexp(expression("a | b + c")), "(+ (| a b) c)")
+            self.assertEqual(sexp(expression("a * b | c")), "(| (* a b) c)")
+        finally:
+            ExprParser.extensions.remove(pipe)
+
+        self.assertNotIn("PIPE", INFIX)
+        with self.assertRaises(QuarkError):
+            expression("a | b")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,46 @@
+import unittest
+from core import build
+from core.helper_types import NodeType
+from core.validator import validate
+from core.diagnostics import QuarkError
+from tests.helpers import expression, parse, sexp
+
+
+class RangeTest(unittest.TestCase):
+    def test_bounds(self):
+        cases = {
+            "1..5": "(.. 1 5)",
+            "1..=5": "(..= 1 5)",
+            "n..": "(.. n _)",
+            "..n": "(.. _ n)",
+            "..=n": "(..= _ n)",
+        }
+        for text, shape in cases.items():
+            with self.subTest(text=text):
+                self.assertEqual(sexp(expression(text)), shape)
+
+    def test_open_bounds_are_nodes(self):
+        # Tree consumers such as the native bridge expect a node in every slot
+        tree = parse("a = 1..\nb = ..2\n")
+        for node, _ in tree.walk():
+            self.assertNotIn(None, node.children, str(node))
+
+    def test_inclusive_range_needs_end(self):
+        with self.assertRaises(QuarkError) as ctx:
+            parse("a = 1..=\n")
+        self.assertEqual(ctx.exception.code, "QK0009")
+
+    def test_builder_matches_parser(self):
+        self.assertEqual(build.range_(start="n"), expression("n.."))
+        self.assertEqual(build.range_(end="n", inclusive=True), expression("..=n"))
+
+    def test_validator(self):
+        unit = build.compilation_unit(build.range_())
+        self.assertTrue(any("Unbounded" in e for e in validate(unit)))
+        inclusive = build.compilation_unit(build.range_(start=1, inclusive=True))
+        self.assertTrue(any("end bound" in e for e in validate(inclusive)))
+        self.assertEqual(validate(build.compilation_unit(build.range_(start="a"))), [])
+
+
+if __name__ == "__main__":
+    unittest.main()
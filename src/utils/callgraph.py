@@ -0,0 +1,73 @@
+import json
+from core.helper_types import NodeType
+
+# Caller name for calls made outside any function
+MODULE = "<module>"
+
+
+def _functions(block):
+    # Functions defined directly in a block, by name
+    return {
+        s.children[0].tok.value: s
+        for s in (block.children if block else [])
+        if s.type == NodeType.Function
+    }
+
+
+def build(tree):
+    """Static call graph: {"functions": {name: line}, "calls": [(caller,
+    callee, line)]}. Function names are qualified by their enclosing
+    functions, e.g. `outer.inner`; a callee that is not defined in any
+    enclosing scope keeps its bare name and counts as external."""
+    functions, calls = {}, []
+
+    def visit(node, caller, scopes):
+        match node.type:
+            case NodeType.Function:
+                name = node.children[0].tok.value
+                qualified = name if caller == MODULE else f"{caller}.{name}"
+                functions[qualified] = node.tok.lineno
+                body = node.children[2]
+                # Nested functions are visible to each other and to the body
+                inner = {n: f"{qualified}.{n}" for n in _functions(body)}
+                for child in node.children[2:]:
+                    visit(child, qualified, [inner] + scopes)
+                return
+            case NodeType.FunctionCall:
+                callee = node.children[0].tok
+                target = next((s[callee.value] for s in scopes if callee.value in s), callee.value)
+                calls.append((caller, target, callee.lineno))
+        for child in node.children:
+            if child:
+                visit(child, caller, scopes)
+
+    top = tree.children[0] if tree.children else None
+    visit(tree, MODULE, [{n: n for n in _functions(top)}])
+    return {"functions": functions, "calls": calls}
+
+
+def to_json(graph):
+    return json.dumps(
+        {
+            "functions": [{"name": n, "line": l} for n, l in graph["functions"].items()],
+            "calls": [
+                {"caller": a, "callee": b, "line": l, "external": b not in graph["functions"]}
+                for a, b, l in graph["calls"]
+            ],
+        },
+        indent=2,
+    )
+
+
+def to_dot(graph):
+    lines = ["digraph calls {", "\tnode [shape=rectangle];"]
+    external = {b for _, b, _ in graph["calls"] if b not in graph["functions"]}
+    for name in graph["functions"]:
+        lines.append(f'\t"{name}";')
+    for name in sorted(external):
+        lines.append(f'\t"{name}" [style=dashed];')
+    # One edge per caller/callee pair, however many call sites
+    for caller, callee in dict.fromkeys((a, b) for a, b, _ in graph["calls"]):
+        lines.append(f'\t"{caller}" -> "{callee}";')
+    lines.append("}")
+    return "\n".join(lines)
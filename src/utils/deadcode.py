@@ -0,0 +1,67 @@
+import json
+
+
+def imported_modules(record):
+    return {module for module, _ in record["imports"].values()}
+
+
+def reachable(modules, entries):
+    """Modules reachable through `use` from the `entries`."""
+    seen, pending = set(), list(entries)
+    while pending:
+        module = pending.pop()
+        if module in seen or module not in modules:
+            continue
+        seen.add(module)
+        pending.extend(imported_modules(modules[module]))
+    return seen
+
+
+def find(index, entries=None):
+    """Unreachable modules and unused functions in a project index. Without
+    `entries`, every module that no other module imports is an entry point.
+    A function is used if top-level code of a reachable module refers to
+    it, or a used function does (directly or from a nested function), so
+    functions only called by dead ones are dead too.
+
+    Modules that failed to parse are skipped: what they use is unknown, so
+    they are neither reported nor trusted as entry points, and are listed
+    under `skipped_modules` instead."""
+    skipped = sorted(m for m, r in index["modules"].items() if r.get("error"))
+    modules = {m: r for m, r in index["modules"].items() if not r.get("error")}
+    if not entries:
+        imported = set().union(*(imported_modules(r) for r in modules.values()))
+        entries = [m for m in modules if m not in imported]
+    live_modules = reachable(modules, entries)
+
+    def uses(module, function):
+        for ref in modules[module]["references"]:
+            owner = ref["function"] and ref["function"].split(".")[0]
+            if owner == function and not ref["import"]:
+                yield ref["module"], ref["name"]
+
+    live, pending = set(), [u for m in live_modules for u in uses(m, None)]
+    while pending:
+        symbol = pending.pop()
+        if symbol in live:
+            continue
+        live.add(symbol)
+        module, name = symbol
+        if module in modules:
+            pending.extend(uses(module, name))
+
+    functions = [
+        {"module": module, "name": name, "line": info["line"], "column": info["column"]}
+        for module in sorted(live_modules)
+        for name, info in modules[module]["definitions"].items()
+        if info["kind"] == "function" and (module, name) not in live
+    ]
+    return {
+        "unreachable_modules": sorted(m for m in modules if m not in live_modules),
+        "unused_functions": functions,
+        "skipped_modules": skipped,
+    }
+
+
+def to_json(report):
+    return json.dumps(report, indent=2)
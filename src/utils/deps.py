@@ -0,0 +1,103 @@
+import os
+import json
+from core.helper_types import NodeType
+
+# Source file extension for modules named in `use`
+EXTENSION = ".qrk"
+
+
+def module_name(node):
+    """Dotted name of a Use node's module child, ignoring any alias."""
+    if node.type == NodeType.Alias:
+        return module_name(node.children[0])
+    if node.type == NodeType.MemberAccess:
+        return f"{module_name(node.children[0])}.{node.children[1].tok.value}"
+    return node.tok.value
+
+
+def imports(tree):
+    """(module name, `use` token) for each top-level use statement."""
+    return [
+        (module_name(s.children[0]), s.tok)
+        for s in tree.children[0].children
+        if s.type == NodeType.Use
+    ]
+
+
+def module_file(root, module):
+    return os.path.join(root, *module.split(".")) + EXTENSION
+
+
+def module_of(root, path):
+    rel = os.path.relpath(os.path.abspath(path), root)
+    return os.path.splitext(rel)[0].replace(os.sep, ".")
+
+
+def build(root, entry, parse):
+    """Import graph reachable from the file `entry`. `parse(path)` returns a
+    tree. Modules with no file under `root` (e.g. the standard library) are
+    leaves marked external. Returns {module: {"file", "imports"}} where
+    imports are (module, token) pairs."""
+    graph = {}
+    pending = [(module_of(root, entry), entry)]
+    while pending:
+        module, path = pending.pop()
+        if module in graph:
+            continue
+        if not os.path.isfile(path):
+            graph[module] = {"file": None, "imports": []}
+            continue
+        deps = imports(parse(path))
+        graph[module] = {"file": path, "imports": deps}
+        pending.extend((dep, module_file(root, dep)) for dep, _ in reversed(deps))
+    return graph
+
+
+def find_cycle(graph):
+    """The first import cycle found, as a list of (module, token) steps
+    where each token is the `use` that imports the next module; or None."""
+    done, stack = set(), []
+
+    def visit(module):
+        if module in done:
+            return None
+        on_stack = [m for m, _ in stack]
+        for dep, tok in graph[module]["imports"]:
+            stack.append((module, tok))
+            if dep in on_stack + [module]:
+                start = ([m for m, _ in stack]).index(dep)
+                return stack[start:]
+            cycle = visit(dep)
+            if cycle:
+                return cycle
+            stack.pop()
+        done.add(module)
+        return None
+
+    for module in graph:
+        cycle = visit(module)
+        if cycle:
+            return cycle
+    return None
+
+
+def to_dot(graph):
+    lines = ["digraph deps {", "\tnode [shape=rectangle];"]
+    for module, info in graph.items():
+        style = " [style=dashed]" if info["file"] is None else ""
+        lines.append(f'\t"{module}"{style};')
+    for module, info in graph.items():
+        for dep in dict.fromkeys(d for d, _ in info["imports"]):
+            lines.append(f'\t"{module}" -> "{dep}";')
+    lines.append("}")
+    return "\n".join(lines)
+
+
+def to_json(graph):
+    return json.dumps(
+        {
+            module: {"file": info["file"], "imports": [d for d, _ in info["imports"]]}
+            for module, info in graph.items()
+        },
+        indent=2,
+    )
@@ -0,0 +1,74 @@
+import json
+from collections import defaultdict
+from core.helper_types import NodeType
+from core.lints import start, end
+
+N = NodeType
+
+
+def shape(node, abstract=False):
+    """Structural key of a subtree. With `abstract`, identifier names and
+    literal values are ignored, so renamed copies still match."""
+    tok = None
+    if node.tok:
+        value = node.tok.value
+        if abstract and node.type in [N.Identifier, N.Literal]:
+            value = None
+        tok = (node.tok.type, value)
+    kids = tuple(shape(c, abstract) if c else None for c in node.children)
+    return (node.type, tok, kids)
+
+
+def size(node):
+    return sum(1 for _ in node.walk())
+
+
+def find(files, abstract=False, min_nodes=12):
+    """Clone groups across `files`, a list of (path, tree, LineIndex).
+    Subtrees smaller than `min_nodes` are ignored, as is a group whose
+    copies all sit inside the copies of a larger group. Each group is a
+    list of {"file", "start", "end", "nodes"} sorted by location."""
+    groups = defaultdict(list)
+    for path, tree, lines in files:
+        for node, nodes in tree.walk():
+            # Whole-file and whole-body matches say nothing useful
+            if node.type in [N.CompilationUnit, N.Block]:
+                continue
+            n = size(node)
+            if n >= min_nodes:
+                groups[shape(node, abstract)].append((path, node, nodes[:-1], lines, n))
+
+    clones = [g for g in groups.values() if len(g) > 1]
+    members = {id(node) for g in clones for _, node, _, _, _ in g}
+    report = []
+    for group in clones:
+        if all(any(id(p) in members for p in parents) for _, _, parents, _, _ in group):
+            continue
+        report.append(
+            sorted(
+                (
+                    {
+                        "file": path,
+                        "start": lines.line_col(start(node).pos)[0],
+                        "end": lines.line_col(end(node) - 1)[0],
+                        "nodes": n,
+                    }
+                    for path, node, _, lines, n in group
+                ),
+                key=lambda c: (c["file"], c["start"]),
+            )
+        )
+    # Biggest clones first
+    return sorted(report, key=lambda g: (-g[0]["nodes"], g[0]["file"], g[0]["start"]))
+
+
+def to_json(groups):
+    return json.dumps(groups, indent=2)
+
+
+def format_text(groups):
+    lines = []
+    for i, group in enumerate(groups, 1):
+        lines.append(f"clone group {i} ({group[0]['nodes']} nodes, {len(group)} copies):")
+        lines.extend(f"\t{c['file']}:{c['start']}-{c['end']}" for c in group)
+    return "\n".join(lines)
@@ -0,0 +1,118 @@
+import re
+import html
+import json
+from core import lex_grammar
+from core.options import EDITIONS
+from core.diagnostics import QuarkError
+from utils import themes
+
+# Keywords depend on the edition; see categories()
+CATEGORIES = {
+    "number": {"INT", "FLOAT"},
+    "string": {"STR", "QUOTES", "DQUOTES"},
+    "identifier": {"ID"},
+    "punctuation": {
+        "LPAR",
+        "RPAR",
+        "LSQUARE",
+        "RSQUARE",
+        "LBRACE",
+        "RBRACE",
+        "COMMA",
+        "COLON",
+        "DOT",
+    },
+}
+
+ANSI = {
+    "keyword": "\033[35m",
+    "number": "\033[36m",
+    "string": "\033[32m",
+    "comment": "\033[90m",
+    "operator": "\033[33m",
+}
+
+COMMENT_RE = re.compile(lex_grammar.t_ignore_COMMENT)
+
+
+def categories(edition=EDITIONS[-1]):
+    return {"keyword": set(lex_grammar.KEYWORDS[edition].values()), **CATEGORIES}
+
+
+def classify(tok_type, edition=EDITIONS[-1]):
+    if tok_type in ["NEWLINE", "WS"]:
+        return None
+    for category, types in categories(edition).items():
+        if tok_type in types:
+            return category
+    return "operator"
+
+
+def spans(lexer, source, edition=EDITIONS[-1], errors=None):
+    """Yields (category, text) pairs covering the whole source. Text the
+    lexer rejects, and everything after it, is yielded uncolored; the
+    QuarkError is appended to `errors` if given."""
+    lexer.paren_count, lexer.at_line_start = 0, False
+    lexer.keywords = lex_grammar.KEYWORDS[edition]
+    lexer.input(source)
+    pos = 0
+    try:
+        for tok in iter(lexer.token, None):
+            yield from _gap(source[pos : tok.pos])
+            yield classify(tok.type, edition), source[tok.pos : lexer.pos]
+            pos = lexer.pos
+    except QuarkError as err:
+        if errors is not None:
+            errors.append(err)
+        yield None, source[pos:]
+        return
+    yield from _gap(source[pos:])
+
+
+def _gap(text):
+    # Whitespace and comments never reach the token stream
+    m = COMMENT_RE.search(text)
+    if m:
+        yield None, text[: m.start()]
+        yield "comment", m.group()
+        yield None, text[m.end() :]
+    elif text:
+        yield None, text
+
+
+def render_ansi(parts):
+    return "".join(
+        f"{ANSI[cat]}{text}\033[0m" if cat in ANSI else text for cat, text in parts
+    )
+
+
+def render_html(parts, theme=None):
+    body = "".join(
+        f'<span class="qk-{cat}">{html.escape(text)}</span>'
+        if cat
+        else html.escape(text)
+        for cat, text in parts
+    )
+    style = f"<style>\n{themes.css(theme)}\n</style>\n" if theme else ""
+    return f'{style}<pre class="quark">{body}</pre>'
+
+
+def textmate_grammar(edition=EDITIONS[-1]):
+    keywords = "|".join(sorted(lex_grammar.KEYWORDS[edition]))
+    patterns = [
+        {"name": "comment.line.double-slash.quark", "match": lex_grammar.t_ignore_COMMENT},
+        {"name": "string.quoted.double.quark", "match": lex_grammar.t_STR},
+        {"name": "keyword.control.quark", "match": rf"\b({keywords})\b"},
+        {"name": "constant.numeric.float.quark", "match": lex_grammar.t_FLOAT.__doc__},
+        {"name": "constant.numeric.integer.quark", "match": rf"\b{lex_grammar.t_INT.__doc__}\b"},
+        {"name": "variable.other.quark", "match": lex_grammar.t_ID.__doc__},
+    ]
+    return json.dumps(
+        {
+            "name": "Quark",
+            "scopeName": "source.quark",
+            "fileTypes": ["qrk"],
+            "patterns": patterns,
+        },
+        indent=2,
+    )
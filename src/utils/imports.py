@@ -0,0 +1,92 @@
+import os
+import re
+from core import lex_grammar
+from core.helper_types import NodeType
+from core.lints import Linter, Scope, start, end
+from core.session import LineIndex
+from utils import deps
+
+N = NodeType
+
+
+def _name(node):
+    """(name, alias or None) of a Use child."""
+    if node.type == N.Alias:
+        return deps.module_name(node.children[0]), node.tok.value
+    return deps.module_name(node), None
+
+
+def _render(module, alias, names):
+    text = f"use {module}" + (f" as {alias}" if alias else "")
+    if names:
+        text += ": " + ", ".join(n + (f" as {a}" if a else "") for n, a in names)
+    return text
+
+
+def organize(tree, source, root):
+    """The text of the `use` block of `tree` rewritten with unused imports
+    removed, selections of one module merged and sorted, and standard
+    modules (no file under `root`) grouped before project ones. Returns
+    (start offset, end offset, replacement, removed names), or raises
+    ValueError when the imports cannot be rewritten safely."""
+    uses = [s for s in tree.children[0].children if s.type == N.Use]
+    if not uses:
+        return None
+    lines = LineIndex(source)
+    first = lines.line_col(start(uses[0]).pos)[0]
+    last = lines.line_col(end(uses[-1]) - 1)[0]
+
+    # Only blank lines may sit between the imports, and the imports carry
+    # no comments; anything else would be moved or lost
+    covered = set()
+    for use in uses:
+        a, b = lines.line_col(start(use).pos)[0], lines.line_col(end(use) - 1)[0]
+        if a == b and any(
+            lines.line_col(start(s).pos)[0] == a for s in tree.children[0].children if s not in uses
+        ):
+            raise ValueError(f"line {a} holds an import and other code")
+        covered.update(range(a, b + 1))
+    for line in range(first, last + 1):
+        lo, hi = lines.line_span(line)
+        if line not in covered and source[lo:hi].strip():
+            raise ValueError(f"line {line} between imports is not an import")
+        # A use statement holds no strings, so `//` on its lines starts a comment
+        if line in covered and re.search(lex_grammar.t_ignore_COMMENT, source[lo:hi]):
+            raise ValueError(f"line {line} has a comment on an import")
+
+    scope = Scope()
+    Linter(source).visit(tree, scope)
+    used = scope.reads
+
+    # Whole-module imports as (module, alias), and selected names by module;
+    # `use m` and `use m: x` are kept apart so neither absorbs the other
+    wholes, selections, removed = set(), {}, []
+    for use in uses:
+        module, alias = _name(use.children[0])
+        if len(use.children) == 1:
+            bound = alias or module.split(".")[-1]
+            if bound in used:
+                wholes.add((module, alias))
+            else:
+                removed.append(bound)
+            continue
+        for child in use.children[1:]:
+            name, name_alias = _name(child)
+            if (name_alias or name) in used:
+                selections.setdefault(module, []).append((name, name_alias))
+            else:
+                removed.append(name_alias or name)
+
+    groups = [[], []]
+    keys = wholes | {(module, None) for module in selections}
+    for module, alias in sorted(keys, key=lambda k: (k[0], k[1] or "")):
+        project = os.path.isfile(deps.module_file(root, module))
+        if (module, alias) in wholes:
+            groups[project].append(_render(module, alias, None))
+        if alias is None and module in selections:
+            names = sorted(dict.fromkeys(selections[module]), key=lambda n: (n[0], n[1] or ""))
+            groups[project].append(_render(module, None, names))
+    text = "\n\n".join("\n".join(group) for group in groups if group)
+
+    lo, hi = lines.line_span(first)[0], lines.line_span(last)[1]
+    return lo, hi, text, removed
@@ -0,0 +1,255 @@
+import os
+import json
+from core.helper_types import NodeType
+from core.session import LineIndex
+from utils import deps
+
+N = NodeType
+
+# Written to the project root; bump VERSION when the layout changes so old
+# indexes are rebuilt rather than misread
+INDEX_FILE = ".quark-index.json"
+VERSION = 2
+
+
+def _span(lines, tok):
+    line, column = lines.line_col(tok.pos)
+    return {"line": line, "column": column, "pos": tok.pos, "end": tok.end}
+
+
+def _locals(function):
+    """Parameters and names bound in a function body, nested functions
+    included but not their own locals."""
+    names = {p.tok.value for p in function.children[1].children}
+
+    def visit(node):
+        if node.type == N.Function:
+            names.add(node.children[0].tok.value)
+            return
+        if node.type == N.Assignment:
+            target = node.children[0]
+            if target.type != N.MemberAccess:
+                names.update(t.tok.value for t, _ in target.walk() if t.type == N.Identifier)
+        for child in node.children:
+            if child:
+                visit(child)
+
+    visit(function.children[2])
+    return names
+
+
+def _imports(use):
+    """Names a Use node binds, as name -> (module, symbol); symbol is None
+    when the name is the module itself."""
+    target = use.children[0]
+    module = deps.module_name(target)
+    if len(use.children) == 1:
+        if target.type == N.Alias:
+            return {target.tok.value: (module, None)}
+        return {module.split(".")[-1]: (module, None)}
+    names = {}
+    for child in use.children[1:]:
+        if child.type == N.Alias:
+            names[child.tok.value] = (module, child.children[0].tok.value)
+        else:
+            names[child.tok.value] = (module, child.tok.value)
+    return names
+
+
+def definitions(tree, lines):
+    """Top-level names, with their kind and span. Assignments inside
+    top-level ifs count; anything inside a function does not."""
+    defs = {}
+
+    def define(tok, kind):
+        defs.setdefault(tok.value, {"kind": kind, **_span(lines, tok)})
+
+    def visit(node):
+        match node.type:
+            case N.Function:
+                define(node.children[0].tok, "function")
+                return
+            case N.Enum | N.Struct:
+                define(node.children[0].tok, str(node.type).lower())
+                return
+            case N.Assignment:
+                target = node.children[0]
+                if target.type != N.MemberAccess:
+                    for t, _ in target.walk():
+                        if t.type == N.Identifier:
+                            define(t.tok, "variable")
+        for child in node.children:
+            if child:
+                visit(child)
+
+    visit(tree.children[0])
+    return defs
+
+
+def module_record(module, tree, source):
+    """Definitions, imports and references to top-level symbols (of this
+    module or an imported one) for one parsed file."""
+    lines = LineIndex(source)
+    defs = definitions(tree, lines)
+    imported = {}
+    for stmt, _ in tree.walk():
+        if stmt.type == N.Use:
+            imported.update(_imports(stmt))
+    refs = []
+
+    def ref(target, name, tok, function, imported=False):
+        refs.append(
+            {
+                "module": target,
+                "name": name,
+                **_span(lines, tok),
+                "function": function,
+                # Naming a symbol in `use m: name` is not a use of it
+                "import": imported,
+            }
+        )
+
+    # A name refers to a top-level symbol unless a function scope binds it
+    def resolve(name, scopes):
+        if any(name in scope for scope in scopes):
+            return None
+        if name in imported:
+            return imported[name]
+        if name in defs:
+            return (module, name)
+        return None
+
+    def visit(node, scopes, function):
+        match node.type:
+            case N.Function:
+                name = node.children[0].tok.value
+                qualified = f"{function}.{name}" if function else name
+                visit(node.children[2], [_locals(node)] + scopes, qualified)
+                for child in node.children[3:]:
+                    visit(child, scopes, function)
+                return
+            case N.Use:
+                for child in node.children[1:]:
+                    tok = child.children[0].tok if child.type == N.Alias else child.tok
+                    ref(imported[child.tok.value][0], tok.value, tok, function, imported=True)
+                return
+            case N.Enum | N.Struct:
+                return
+            case N.Assignment:
+                target, value = node.children
+                visit(value, scopes, function)
+                if target.type == N.MemberAccess:
+                    visit(target, scopes, function)
+                return
+            case N.MemberAccess:
+                base, member = node.children
+                found = resolve(base.tok.value, scopes) if base.type == N.Identifier else None
+                if found and found[1] is None:
+                    # `mod.name` where `mod` is an imported module
+                    ref(found[0], member.tok.value, member.tok, function)
+                else:
+                    visit(base, scopes, function)
+                return
+            case N.Identifier:
+                found = resolve(node.tok.value, scopes)
+                if found and found[1] is not None:
+                    ref(*found, node.tok, function)
+                return
+        for child in node.children:
+            if child:
+                visit(child, scopes, function)
+
+    visit(tree.children[0], [], None)
+    return {
+        "definitions": defs,
+        "imports": {name: list(target) for name, target in imported.items()},
+        "references": refs,
+    }
+
+
+def project_files(root):
+    for dirpath, dirnames, filenames in os.walk(root):
+        dirnames[:] = sorted(d for d in dirnames if not d.startswith("."))
+        for name in sorted(filenames):
+            if name.endswith(deps.EXTENSION):
+                yield os.path.join(dirpath, name)
+
+
+def load(root):
+    path = os.path.join(root, INDEX_FILE)
+    try:
+        with open(path) as f:
+            index = json.load(f)
+    except (OSError, ValueError):
+        return {"version": VERSION, "modules": {}}
+    if index.get("version") != VERSION:
+        return {"version": VERSION, "modules": {}}
+    return index
+
+
+def save(root, index):
+    with open(os.path.join(root, INDEX_FILE), "w") as f:
+        json.dump(index, f)
+
+
+def update(root, parse, settings=None):
+    """Brings the index under `root` up to date and saves it. Only files
+    whose size or modification time changed are reparsed, unless the
+    `settings` the files are parsed with (edition, features) differ from
+    the last run, which reparses everything. `parse(path)` returns (tree,
+    source text), or None for a file that fails to parse; such a file is
+    indexed with no symbols until it is fixed. Returns the index and the
+    list of reparsed modules."""
+    index = load(root)
+    settings = settings or {}
+    old = index["modules"] if index.get("settings") == settings else {}
+    index["settings"] = settings
+    modules, reparsed = {}, []
+    for path in project_files(root):
+        module = deps.module_of(root, path)
+        stat = os.stat(path)
+        stamp = [stat.st_mtime_ns, stat.st_size]
+        if module in old and old[module]["stamp"] == stamp:
+            modules[module] = old[module]
+            continue
+        reparsed.append(module)
+        parsed = parse(path)
+        record = (
+            module_record(module, *parsed)
+            if parsed
+            else {"definitions": {}, "imports": {}, "references": [], "error": True}
+        )
+        modules[module] = {"file": os.path.relpath(path, root), "stamp": stamp, **record}
+    index["modules"] = modules
+    if reparsed or modules.keys() != old.keys():
+        save(root, index)
+    return index, reparsed
+
+
+def definition(index, module, name):
+    return index["modules"].get(module, {}).get("definitions", {}).get(name)
+
+
+def references(index, module, name):
+    """Every (referring module, reference) to `module`'s top-level `name`."""
+    return [
+        (source, ref)
+        for source, record in index["modules"].items()
+        for ref in record["references"]
+        if ref["module"] == module and ref["name"] == name
+    ]
+
+
+def symbol_at(index, module, pos):
+    """(module, name) of the top-level symbol defined or referenced at
+    offset `pos` in `module`, or None."""
+    record = index["modules"].get(module)
+    if not record:
+        return None
+    for name, info in record["definitions"].items():
+        if info["pos"] <= pos < info["end"]:
+            return module, name
+    for ref in record["references"]:
+        if ref["pos"] <= pos < ref["end"]:
+            return ref["module"], ref["name"]
+    return None
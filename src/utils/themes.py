@@ -0,0 +1,119 @@
+import copy
+
+# Styles shared by the tree (DOT) and source (HTML) renderers. `nodes` maps
+# a node type name, or "default", to graphviz node attributes; `tokens` maps
+# a highlight category to a colour.
+THEMES = {
+    "light": {
+        "background": "#ffffff",
+        "foreground": "#1f1f1f",
+        "nodes": {
+            "default": {
+                "shape": "rectangle",
+                "style": "filled",
+                "fillcolor": "#ffffff",
+                "color": "#1f1f1f",
+                "fontcolor": "#1f1f1f",
+                "fontname": "Helvetica",
+            },
+            "Function": {"fillcolor": "#dbe9ff"},
+            "Condition": {"fillcolor": "#fff2cc"},
+            "Assignment": {"fillcolor": "#fde2e2"},
+            "Operator": {"fillcolor": "#fde2e2"},
+            "Literal": {"fillcolor": "#e2f0d9"},
+            "Identifier": {"fillcolor": "#f2f2f2"},
+        },
+        "tokens": {
+            "keyword": "#7a3e9d",
+            "number": "#1c7c9c",
+            "string": "#2e7d32",
+            "comment": "#8a8a8a",
+            "operator": "#b26b00",
+            "identifier": "#1f1f1f",
+            "punctuation": "#555555",
+        },
+    },
+    "dark": {
+        "background": "#1e1e1e",
+        "foreground": "#d4d4d4",
+        "nodes": {
+            "default": {
+                "shape": "rectangle",
+                "style": "filled",
+                "fillcolor": "#2d2d2d",
+                "color": "#858585",
+                "fontcolor": "#d4d4d4",
+                "fontname": "Helvetica",
+            },
+            "Function": {"fillcolor": "#264f78"},
+            "Condition": {"fillcolor": "#4d4226"},
+            "Assignment": {"fillcolor": "#5a2d2d"},
+            "Operator": {"fillcolor": "#5a2d2d"},
+            "Literal": {"fillcolor": "#2d4a2d"},
+            "Identifier": {"fillcolor": "#333333"},
+        },
+        "tokens": {
+            "keyword": "#c586c0",
+            "number": "#b5cea8",
+            "string": "#ce9178",
+            "comment": "#6a9955",
+            "operator": "#d7ba7d",
+            "identifier": "#9cdcfe",
+            "punctuation": "#d4d4d4",
+        },
+    },
+    "high-contrast": {
+        "background": "#000000",
+        "foreground": "#ffffff",
+        "nodes": {
+            "default": {
+                "shape": "rectangle",
+                "style": "filled",
+                "fillcolor": "#000000",
+                "color": "#ffffff",
+                "fontcolor": "#ffffff",
+                "fontname": "Helvetica-Bold",
+                "penwidth": "2",
+            },
+            "Function": {"color": "#ffff00", "fontcolor": "#ffff00"},
+            "Condition": {"color": "#00ffff", "fontcolor": "#00ffff"},
+        },
+        "tokens": {
+            "keyword": "#ffff00",
+            "number": "#00ffff",
+            "string": "#00ff00",
+            "comment": "#c0c0c0",
+            "operator": "#ff80ff",
+            "identifier": "#ffffff",
+            "punctuation": "#ffffff",
+        },
+    },
+}
+
+
+def resolve(name, styles=None):
+    """The theme called `name` with the user's `styles` (from the viz.styles
+    config table, same shape as a theme) merged over it."""
+    if name not in THEMES:
+        raise SystemExit(f"Unknown theme '{name}', expected one of {', '.join(THEMES)}.")
+    theme = copy.deepcopy(THEMES[name])
+    for key, val in (styles or {}).items():
+        if isinstance(val, dict):
+            for item, attrs in val.items():
+                if isinstance(attrs, dict):
+                    theme[key].setdefault(item, {}).update(attrs)
+                else:
+                    theme[key][item] = attrs
+        else:
+            theme[key] = val
+    return theme
+
+
+def node_style(theme, node_type):
+    return {**theme["nodes"]["default"], **theme["nodes"].get(str(node_type), {})}
+
+
+def css(theme):
+    rules = [f"pre.quark {{ background: {theme['background']}; color: {theme['foreground']}; }}"]
+    rules += [f".qk-{cat} {{ color: {color}; }}" for cat, color in theme["tokens"].items()]
+    return "\n".join(rules)
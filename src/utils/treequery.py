@@ -0,0 +1,58 @@
+import re
+from dataclasses import dataclass
+
+# Selector syntax: compound selectors joined by whitespace (descendant) or
+# '>' (direct child). A compound is a NodeType name or '*', optionally
+# followed by '[value]' to match the node's token value.
+STEP_RE = re.compile(r"(\*|\w+)(?:\[([^\]]*)\])?$")
+
+
+@dataclass(frozen=True)
+class Step:
+    combinator: str
+    type: str
+    value: str = None
+
+    def matches(self, node):
+        if self.type != "*" and str(node.type) != self.type:
+            return False
+        if self.value is not None:
+            return node.tok is not None and str(node.tok.value) == self.value
+        return True
+
+
+def parse_selector(selector):
+    steps, combinator = [], " "
+    for part in selector.replace(">", " > ").split():
+        if part == ">":
+            combinator = ">"
+            continue
+
+        m = STEP_RE.match(part)
+        if not m:
+            raise ValueError(f"Invalid selector step '{part}'.")
+        steps.append(Step(combinator, m.group(1), m.group(2)))
+        combinator = " "
+
+    if not steps or combinator == ">":
+        raise ValueError(f"Incomplete selector '{selector}'.")
+    return steps
+
+
+def _match(path, i, steps, j):
+    if not steps[j].matches(path[i]):
+        return False
+    if j == 0:
+        return True
+    if steps[j].combinator == ">":
+        return i > 0 and _match(path, i - 1, steps, j - 1)
+    return any(_match(path, k, steps, j - 1) for k in range(i - 1, -1, -1))
+
+
+def query(tree, selector):
+    steps = parse_selector(selector)
+    return [
+        node
+        for node, path in tree.walk()
+        if _match(path, len(path) - 1, steps, len(steps) - 1)
+    ]
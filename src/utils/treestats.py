@@ -0,0 +1,37 @@
+from collections import Counter
+from core.helper_types import NodeType
+
+
+def _depth(node):
+    return 1 + max((_depth(c) for c in node.children if c), default=0)
+
+
+def collect(tree, tokens):
+    nodes = [node for node, _ in tree.walk()]
+    functions = [n for n in nodes if n.type == NodeType.Function]
+    # A function body is the Block child; its length is its statement count
+    lengths = [
+        len(next(c for c in f.children if c.type == NodeType.Block).children)
+        for f in functions
+    ]
+
+    return {
+        "nodes": dict(Counter(str(n.type) for n in nodes)),
+        "total_nodes": len(nodes),
+        "max_depth": _depth(tree),
+        "functions": len(functions),
+        "avg_function_length": sum(lengths) / len(lengths) if lengths else 0,
+        "tokens": dict(Counter(t.type for t in tokens)),
+        "total_tokens": len(tokens),
+    }
+
+
+def format_text(stats):
+    lines = []
+    for key, val in stats.items():
+        if isinstance(val, dict):
+            lines.append(f"{key}:")
+            lines.extend(f"\t{k}: {v}" for k, v in sorted(val.items()))
+        else:
+            lines.append(f"{key}: {val}")
+    return "\n".join(lines)
@@ -1,35 +1,89 @@
 from gvgen import *
+from core.helper_types import NodeType
+from utils import themes
 
 
 class TreeViz:
-    def __init__(self):
+    def __init__(
+        self, clusters=True, max_label=None, merge_chains=False, max_nodes=None, theme=None
+    ):
         self.graph = GvGen()
         self.graph.styleDefaultAppend("shape", "rectangle")
+        self.theme = theme
+        # gvgen style names already defined, one per node type
+        self.styles = set()
+        # Draw each Function's subtree inside a box labeled with its name
+        self.clusters = clusters
+        # Size controls for large programs; None means no limit
+        self.max_label = max_label
+        self.merge_chains = merge_chains
+        self.max_nodes = max_nodes
+        self.count = 0
 
-    def _new(self, tree):
+    def _label(self, tree):
+        if not tree.tok:
+            return f"{tree.type}"
         val = (
-            (
-                tree.tok.value.replace('"', "").replace(",", "")
-                if type(tree.tok.value) == str
-                else tree.tok.value
-            )
-            if tree.tok
-            else ""
+            tree.tok.value.replace('"', "").replace(",", "")
+            if type(tree.tok.value) == str
+            else f"{tree.tok.value}"
         )
-        return self.graph.newItem(f"{tree}")
+        if self.max_label and len(val) > self.max_label:
+            val = val[: self.max_label] + "…"
+        return f"{tree.type}[{val}]"
+
+    def _chain(self, tree):
+        """`tree` and its descendants that each have exactly one child, when
+        merging is on; the chain is drawn as a single box."""
+        chain = [tree]
+        while self.merge_chains:
+            kids = [c for c in chain[-1].children if c]
+            if len(kids) != 1 or (self.clusters and kids[0].type == NodeType.Function):
+                break
+            chain.append(kids[0])
+        return chain
+
+    def _new(self, chain, cluster=None):
+        self.count += 1
+        item = self.graph.newItem("\n".join(map(self._label, chain)), cluster)
+        if self.theme:
+            self._style(item, str(chain[0].type))
+        return item
+
+    def _style(self, item, node_type):
+        if node_type not in self.styles:
+            for attr, val in themes.node_style(self.theme, node_type).items():
+                self.graph.styleAppend(node_type, attr, val)
+            self.styles.add(node_type)
+        self.graph.styleApply(node_type, item)
 
     def _link(self, node1, node2):
         self.graph.newLink(node1, node2)
 
-    def generate(self, tree, parent=None):
+    def _cluster(self, function, cluster):
+        # gvgen draws an item with children as a cluster subgraph
+        return self.graph.newItem(f"fn {function.children[0].tok.value}", cluster)
+
+    def _full(self):
+        return self.max_nodes is not None and self.count >= self.max_nodes
+
+    def generate(self, tree, parent=None, cluster=None):
         if tree:
-            node = self._new(tree) if not parent else parent
-            for child in tree.children:
-                if child:
-                    node1 = self._new(child)
-                    self._link(node, node1)
-                    self.generate(child, node1)
-
-    def save(self):
-        outf = open("treeviz.dot", "w+")
+            chain = self._chain(tree)
+            node = self._new(chain, cluster) if not parent else parent
+            kids = [c for c in chain[-1].children if c]
+            for i, child in enumerate(kids):
+                if self._full():
+                    hidden = sum(1 for k in kids[i:] for _ in k.walk())
+                    self._link(node, self.graph.newItem(f"… {hidden} more", cluster))
+                    break
+                inner = cluster
+                if self.clusters and child.type == NodeType.Function:
+                    inner = self._cluster(child, cluster)
+                node1 = self._new(self._chain(child), inner)
+                self._link(node, node1)
+                self.generate(child, node1, inner)
+
+    def save(self, path="treeviz.dot"):
+        outf = open(path, "w+")
         self.graph.dot(outf)